@@ -0,0 +1,187 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::{ParseError, Solution};
+
+/// How many times to run each piece of a solution
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Untimed runs before measuring, to warm up caches and the branch predictor
+    pub warmup: usize,
+    /// Timed runs
+    pub runs: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig {
+            warmup: 1,
+            runs: 10,
+        }
+    }
+}
+
+/// Summary of the timed runs of one piece of a solution
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// Summarize a set of timings. Uses the nearest rank for the percentiles.
+    pub fn from_timings(timings: &[Duration]) -> Stats {
+        assert!(!timings.is_empty(), "Need at least one timing");
+        let mut sorted = timings.to_vec();
+        sorted.sort_unstable();
+
+        let rank = |pct: usize| sorted[((sorted.len() * pct).div_ceil(100)).max(1) - 1];
+        Stats {
+            min: sorted[0],
+            median: rank(50),
+            p95: rank(95),
+        }
+    }
+
+    fn to_json(self) -> String {
+        format!(
+            r#"{{"min": {:.3}, "median": {:.3}, "p95": {:.3}}}"#,
+            micros(self.min),
+            micros(self.median),
+            micros(self.p95)
+        )
+    }
+}
+
+/// Timings of parsing and both parts of one day
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DayBench {
+    pub day: u8,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+/// Every timing is reported in microseconds, so that days can be compared at a glance
+pub fn micros(d: Duration) -> f64 {
+    d.as_secs_f64() * 1e6
+}
+
+/// Time `f` `config.runs` times, after `config.warmup` untimed runs
+fn time_runs<T>(config: BenchConfig, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..config.warmup {
+        black_box(f());
+    }
+    let timings: Vec<Duration> = (0..config.runs.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            let result = black_box(f());
+            // Stop the clock before the result is dropped, so freeing it isn't timed
+            let elapsed = start.elapsed();
+            drop(result);
+            elapsed
+        })
+        .collect();
+    Stats::from_timings(&timings)
+}
+
+/// Time parsing `input_str`, then time each part on the parsed input
+pub fn bench<S: Solution>(input_str: &str, config: BenchConfig) -> Result<DayBench, ParseError> {
+    // Parse once up front, so that bad input is reported instead of timed
+    let input = S::parse(input_str)?;
+
+    let parse = time_runs(config, || S::parse(black_box(input_str)));
+    let part1 = time_runs(config, || S::part1(black_box(&input)));
+    let part2 = time_runs(config, || S::part2(black_box(&input)));
+
+    Ok(DayBench {
+        day: S::DAY,
+        parse,
+        part1,
+        part2,
+    })
+}
+
+/// A machine readable summary of a set of benchmarks, with every time in microseconds
+pub fn to_json(config: BenchConfig, results: &[DayBench]) -> String {
+    let days: Vec<String> = results
+        .iter()
+        .map(|r| {
+            format!(
+                r#"    {{"day": {}, "parse": {}, "part1": {}, "part2": {}}}"#,
+                r.day,
+                r.parse.to_json(),
+                r.part1.to_json(),
+                r.part2.to_json()
+            )
+        })
+        .collect();
+    format!(
+        "{{\n  \"unit\": \"us\",\n  \"warmup\": {},\n  \"runs\": {},\n  \"days\": [\n{}\n  ]\n}}",
+        config.warmup,
+        config.runs,
+        days.join(",\n")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn test_stats() {
+        let timings: Vec<Duration> = (1..=20).rev().map(ms).collect();
+        let want = Stats {
+            min: ms(1),
+            median: ms(10),
+            p95: ms(19),
+        };
+        assert_eq!(want, Stats::from_timings(&timings));
+
+        let want = Stats {
+            min: ms(7),
+            median: ms(7),
+            p95: ms(7),
+        };
+        assert_eq!(want, Stats::from_timings(&[ms(7)]));
+    }
+
+    #[test]
+    fn test_bench() {
+        let config = BenchConfig { warmup: 0, runs: 3 };
+        let got = bench::<crate::day04::Day04>("2-4,6-8\n2-8,3-7", config).unwrap();
+        assert_eq!(4, got.day);
+        assert!(got.parse.min <= got.parse.median && got.parse.median <= got.parse.p95);
+
+        assert!(bench::<crate::day04::Day04>("2-4,6-8\n2-8", config).is_err());
+    }
+
+    #[test]
+    fn test_to_json() {
+        let stats = Stats {
+            min: Duration::from_nanos(1500),
+            median: ms(2),
+            p95: ms(3),
+        };
+        let results = [DayBench {
+            day: 4,
+            parse: stats,
+            part1: stats,
+            part2: stats,
+        }];
+        let want = r#"{
+  "unit": "us",
+  "warmup": 1,
+  "runs": 10,
+  "days": [
+    {"day": 4, "parse": {"min": 1.500, "median": 2000.000, "p95": 3000.000}, "part1": {"min": 1.500, "median": 2000.000, "p95": 3000.000}, "part2": {"min": 1.500, "median": 2000.000, "p95": 3000.000}}
+  ]
+}"#;
+        assert_eq!(want, to_json(BenchConfig::default(), &results));
+    }
+}
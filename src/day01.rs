@@ -0,0 +1,111 @@
+use itertools::Itertools;
+
+use crate::error::{parse_lines, ParseError};
+use crate::Solution;
+
+pub fn parse(input_str: &str) -> Result<Vec<usize>, ParseError> {
+    // Blank lines separate the rations of one elf from the next
+    let lines: Vec<Option<usize>> = parse_lines(input_str, |line| {
+        if line.is_empty() {
+            return Ok(None);
+        }
+        line.parse()
+            .map(Some)
+            .map_err(|_| ParseError::new(Day01::DAY, line, "a number of calories"))
+    })?;
+
+    Ok(lines
+        .split(Option::is_none)
+        .map(|rations| rations.iter().flatten().sum())
+        // Sort largest to smallest
+        .sorted_unstable_by(|a, b| Ord::cmp(b, a))
+        .collect())
+}
+
+pub fn part1(input: &[usize]) -> usize {
+    // For each inner vector, calculate its sum, then return the max value
+    input[0]
+}
+
+pub fn part2(input: &[usize]) -> usize {
+    // For each inner vector, calculate its sum, then sort them, and calculate the
+    // sum of the largest three
+    input.iter().take(3).sum()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    type Input = Vec<usize>;
+    type Answer = usize;
+
+    fn parse(input_str: &str) -> Result<Self::Input, ParseError> {
+        parse(input_str)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        let input_str = "1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+        let got = parse(input_str).unwrap();
+        let want = vec![24000, 11000, 10000, 6000, 4000];
+        assert_eq!(want, got);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input_str = "1000
+2000
+
+4x00";
+        let want = ParseError {
+            day: 1,
+            line: 4,
+            text: "4x00".to_string(),
+            expected: "a number of calories".to_string(),
+        };
+        assert_eq!(Err(want), parse(input_str));
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = vec![24000, 11000, 10000, 6000, 4000];
+        let want: usize = 24000;
+        let got = part1(&input);
+        assert_eq!(want, got);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = vec![24000, 11000, 10000, 6000, 4000];
+        let want: usize = 45000;
+        let got = part2(&input);
+        assert_eq!(want, got);
+    }
+}
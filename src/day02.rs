@@ -1,44 +1,48 @@
+use crate::error::{parse_lines, ParseError};
+use crate::Solution;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum RPS {
+#[allow(clippy::upper_case_acronyms)]
+pub enum RPS {
     Rock,
     Paper,
     Scissors,
 }
 
 impl RPS {
-    fn parse(s: &str) -> RPS {
+    fn parse(s: &str) -> Result<RPS, ParseError> {
         match s {
-            "A" => RPS::Rock,
-            "B" => RPS::Paper,
-            "C" => RPS::Scissors,
-            "X" => RPS::Rock,
-            "Y" => RPS::Paper,
-            "Z" => RPS::Scissors,
-            _ => panic!("Input not A, B, C, X, Y, or Z"),
+            "A" => Ok(RPS::Rock),
+            "B" => Ok(RPS::Paper),
+            "C" => Ok(RPS::Scissors),
+            "X" => Ok(RPS::Rock),
+            "Y" => Ok(RPS::Paper),
+            "Z" => Ok(RPS::Scissors),
+            _ => Err(ParseError::new(Day02::DAY, s, "A, B, C, X, Y, or Z")),
         }
     }
 }
 
 #[derive(Debug, Clone, Copy)]
-enum Strat {
+pub enum Strat {
     Lost,
     Tie,
     Win,
 }
 
 impl Strat {
-    fn parse(s: &str) -> Strat {
+    fn parse(s: &str) -> Result<Strat, ParseError> {
         match s {
-            "X" => Strat::Lost,
-            "Y" => Strat::Tie,
-            "Z" => Strat::Win,
-            _ => panic!("Input not X, Y, or Z"),
+            "X" => Ok(Strat::Lost),
+            "Y" => Ok(Strat::Tie),
+            "Z" => Ok(Strat::Win),
+            _ => Err(ParseError::new(Day02::DAY, s, "X, Y, or Z")),
         }
     }
 }
 
 #[derive(Debug, PartialEq, Eq)]
-struct RPSPair {
+pub struct RPSPair {
     them: RPS,
     us: RPS,
 }
@@ -67,7 +71,7 @@ impl RPSPair {
 }
 
 #[derive(Debug, Clone, Copy)]
-struct RPSStratPair {
+pub struct RPSStratPair {
     them: RPS,
     strat: Strat,
 }
@@ -96,72 +100,59 @@ impl RPSStratPair {
     }
 }
 
-fn parse_input_part1(input_str: &str) -> Vec<RPSPair> {
-    input_str
-        .trim()
-        .lines()
-        .map(|line| {
-            let parts = line
-                .split_once(' ')
-                .expect("Could not split once around space");
-            RPSPair {
-                them: RPS::parse(parts.0),
-                us: RPS::parse(parts.1),
-            }
+/// Split a line like `A Y` into its two letters
+fn split_round(line: &str) -> Result<(&str, &str), ParseError> {
+    line.split_once(' ')
+        .ok_or_else(|| ParseError::new(Day02::DAY, line, "two letters separated by a space"))
+}
+
+pub fn parse_input_part1(input_str: &str) -> Result<Vec<RPSPair>, ParseError> {
+    parse_lines(input_str.trim_end(), |line| {
+        let parts = split_round(line)?;
+        Ok(RPSPair {
+            them: RPS::parse(parts.0)?,
+            us: RPS::parse(parts.1)?,
         })
-        .collect()
+    })
 }
 
-fn part1(plays: &[RPSPair]) -> usize {
+pub fn part1(plays: &[RPSPair]) -> usize {
     plays.iter().map(|p| p.play_round()).sum()
 }
 
-fn parse_input_part2(input_str: &str) -> Vec<RPSStratPair> {
-    input_str
-        .trim()
-        .lines()
-        .map(|line| {
-            let parts = line
-                .split_once(' ')
-                .expect("Could not split once around space");
-            RPSStratPair {
-                them: RPS::parse(parts.0),
-                strat: Strat::parse(parts.1),
-            }
+pub fn parse_input_part2(input_str: &str) -> Result<Vec<RPSStratPair>, ParseError> {
+    parse_lines(input_str.trim_end(), |line| {
+        let parts = split_round(line)?;
+        Ok(RPSStratPair {
+            them: RPS::parse(parts.0)?,
+            strat: Strat::parse(parts.1)?,
         })
-        .collect()
+    })
 }
 
-fn part2(plays: &[RPSStratPair]) -> usize {
+pub fn part2(plays: &[RPSStratPair]) -> usize {
     plays.iter().map(|p| p.play_round()).sum()
 }
 
-fn main() {
-    let setup_time = std::time::Instant::now();
-
-    // Read the input file for day 1
-    let input_str =
-        std::fs::read_to_string("input/day02.txt").expect("Failed to read day 2 input file");
+pub struct Day02;
 
-    // Parse the input into a vector of numbers
-    let input1 = parse_input_part1(&input_str);
-    let input2 = parse_input_part2(&input_str);
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    /// The same lines read the way each part wants them
+    type Input = (Vec<RPSPair>, Vec<RPSStratPair>);
+    type Answer = usize;
 
-    println!("Setup took {:.6} µs", setup_time.elapsed().as_micros());
-
-    // Part 1
-    let part1_time = std::time::Instant::now();
-    let part1_result = part1(&input1);
-    println!("Part 1 took {:.6} µs", part1_time.elapsed().as_micros());
+    fn parse(input_str: &str) -> Result<Self::Input, ParseError> {
+        Ok((parse_input_part1(input_str)?, parse_input_part2(input_str)?))
+    }
 
-    // Part 2
-    let part2_time = std::time::Instant::now();
-    let part2_result = part2(&input2);
-    println!("Part 2 took {:.6} µs", part2_time.elapsed().as_micros());
+    fn part1(input: &Self::Input) -> Self::Answer {
+        part1(&input.0)
+    }
 
-    println!();
-    println!("Part 1 result: {}", part1_result);
-    println!("Part 2 result: {}", part2_result);
+    fn part2(input: &Self::Input) -> Self::Answer {
+        part2(&input.1)
+    }
 }
 
 #[cfg(test)]
@@ -189,10 +180,40 @@ C Z";
                 us: RPS::Scissors,
             },
         ];
-        let got = parse_input_part1(input_str);
+        let got = parse_input_part1(input_str).unwrap();
         assert_eq!(want, got);
     }
 
+    #[test]
+    fn test_parse_error() {
+        let input_str = "A Y
+B X
+C W";
+        let want = ParseError {
+            day: 2,
+            line: 3,
+            text: "W".to_string(),
+            expected: "A, B, C, X, Y, or Z".to_string(),
+        };
+        assert_eq!(Err(want), parse_input_part1(input_str));
+
+        let want = ParseError {
+            day: 2,
+            line: 3,
+            text: "W".to_string(),
+            expected: "X, Y, or Z".to_string(),
+        };
+        assert_eq!(Err(want), parse_input_part2(input_str).map(|_| ()));
+
+        let want = ParseError {
+            day: 2,
+            line: 1,
+            text: "AY".to_string(),
+            expected: "two letters separated by a space".to_string(),
+        };
+        assert_eq!(Err(want), parse_input_part1("AY"));
+    }
+
     #[test]
     fn test_round1_winner() {
         let p = RPSPair {
@@ -0,0 +1,236 @@
+use std::collections::HashSet;
+
+use crate::error::{parse_lines, ParseError};
+use crate::Solution;
+
+/// The items in each of the two compartments of a rucksack
+pub type Rucksack = (HashSet<char>, HashSet<char>);
+
+pub fn parse_input(input_str: &str) -> Result<Vec<Rucksack>, ParseError> {
+    let packs = parse_lines(input_str, |line| {
+        // Every item has to have a priority, and both compartments hold the same number
+        if !line.chars().all(|c| c.is_ascii_alphabetic()) || line.len() % 2 != 0 {
+            return Err(ParseError::new(
+                Day03::DAY,
+                line,
+                "an even number of letters a-z or A-Z",
+            ));
+        }
+        let h1: HashSet<char> = line.chars().take(line.len() / 2).collect();
+        let h2: HashSet<char> = line.chars().rev().take(line.len() / 2).collect();
+        if h1.is_disjoint(&h2) {
+            return Err(ParseError::new(
+                Day03::DAY,
+                line,
+                "a rucksack with an item in both compartments",
+            ));
+        }
+        Ok((h1, h2))
+    })?;
+
+    // The elves are in groups of three, and each group shares a badge
+    if packs.len() % 3 != 0 {
+        return Err(
+            ParseError::new(Day03::DAY, "", "rucksacks in groups of three")
+                .at_line(packs.len() + 1),
+        );
+    }
+    let lines: Vec<&str> = input_str.lines().collect();
+    for (idx, group) in packs.chunks(3).enumerate() {
+        if badge(group).is_none() {
+            let last = idx * 3 + 2;
+            return Err(ParseError::new(
+                Day03::DAY,
+                lines[last],
+                "a rucksack with an item in common with the two before it",
+            )
+            .at_line(last + 1));
+        }
+    }
+    Ok(packs)
+}
+
+pub fn part1(packs: &[(HashSet<char>, HashSet<char>)]) -> usize {
+    packs.iter().map(|(p1, p2)| single_pack(p1, p2)).sum()
+}
+
+const LETTERS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+pub fn single_pack(p1: &HashSet<char>, p2: &HashSet<char>) -> usize {
+    // Find the overlap of the two hash sets
+    let overlap = *p1
+        .intersection(p2)
+        .next()
+        .expect("parse_input checks every rucksack has an item in both halves");
+    // Find it in the string.
+    LETTERS.find(overlap).expect("Letter is not in alphabet") + 1
+}
+
+pub fn part2(packs: &[(HashSet<char>, HashSet<char>)]) -> usize {
+    packs.chunks(3).map(group_id).sum()
+}
+
+/// The item that every rucksack in a group of three holds, if there is one
+fn badge(chunk: &[(HashSet<char>, HashSet<char>)]) -> Option<char> {
+    // Union the two halves of each line, and then find the intersection of each
+    // group of three
+    let e1: HashSet<char> = chunk[0].0.union(&chunk[0].1).copied().collect();
+    let e2: HashSet<char> = chunk[1].0.union(&chunk[1].1).copied().collect();
+    let e3: HashSet<char> = chunk[2].0.union(&chunk[2].1).copied().collect();
+
+    let temp: HashSet<char> = e1.intersection(&e2).copied().collect();
+    temp.intersection(&e3).next().copied()
+}
+
+pub fn group_id(chunk: &[(HashSet<char>, HashSet<char>)]) -> usize {
+    let overlap = badge(chunk).expect("parse_input checks every group has a badge");
+    LETTERS.find(overlap).expect("Letter is not in alphabet") + 1
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    type Input = Vec<Rucksack>;
+    type Answer = usize;
+
+    fn parse(input_str: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input_str)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let input_str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+        let want = vec![
+            (
+                HashSet::from(['v', 'J', 'r', 'w', 'p', 'W', 't', 'w', 'J', 'g', 'W', 'r']),
+                HashSet::from(['h', 'c', 's', 'F', 'M', 'M', 'f', 'F', 'F', 'h', 'F', 'p']),
+            ),
+            (
+                HashSet::from([
+                    'j', 'q', 'H', 'R', 'N', 'q', 'R', 'j', 'q', 'z', 'j', 'G', 'D', 'L', 'G', 'L',
+                ]),
+                HashSet::from([
+                    'r', 's', 'F', 'M', 'f', 'F', 'Z', 'S', 'r', 'L', 'r', 'F', 'Z', 's', 'S', 'L',
+                ]),
+            ),
+            (
+                HashSet::from(['P', 'm', 'm', 'd', 'z', 'q', 'P', 'r', 'V']),
+                HashSet::from(['v', 'P', 'w', 'w', 'T', 'W', 'B', 'w', 'g']),
+            ),
+            (
+                HashSet::from([
+                    'w', 'M', 'q', 'v', 'L', 'M', 'Z', 'H', 'h', 'H', 'M', 'v', 'w', 'L', 'H',
+                ]),
+                HashSet::from([
+                    'j', 'b', 'v', 'c', 'j', 'n', 'n', 'S', 'B', 'n', 'v', 'T', 'Q', 'F', 'n',
+                ]),
+            ),
+            (
+                HashSet::from(['t', 't', 'g', 'J', 't', 'R', 'G', 'J']),
+                HashSet::from(['Q', 'c', 't', 'T', 'Z', 't', 'Z', 'T']),
+            ),
+            (
+                HashSet::from(['C', 'r', 'Z', 's', 'J', 's', 'P', 'P', 'Z', 's', 'G', 'z']),
+                HashSet::from(['w', 'w', 's', 'L', 'w', 'L', 'm', 'p', 'w', 'M', 'D', 'w']),
+            ),
+        ];
+
+        let got = parse_input(input_str).unwrap();
+        assert_eq!(want, got);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input_str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsS
+PmmdzqPrVvPwwTWBwg";
+        let got = parse_input(input_str).map_err(|e| e.line);
+        assert_eq!(Err(2), got);
+
+        let got = parse_input("vJrwpWtw JgWrhcsF").map_err(|e| e.line);
+        assert_eq!(Err(1), got);
+
+        // Nothing in both compartments
+        let got = parse_input("ab").map_err(|e| (e.line, e.expected));
+        let want = "a rucksack with an item in both compartments".to_string();
+        assert_eq!(Err((1, want)), got);
+
+        // Not a whole number of groups
+        let input_str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn";
+        let got = parse_input(input_str).map_err(|e| e.line);
+        assert_eq!(Err(5), got);
+
+        // A group with no badge
+        let input_str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+aa";
+        let got = parse_input(input_str).map_err(|e| (e.line, e.text));
+        assert_eq!(Err((3, "aa".to_string())), got);
+    }
+
+    #[test]
+    fn test_single_pack() {
+        let input_str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+        let input = parse_input(input_str).unwrap();
+        let want: [usize; 6] = [16, 38, 42, 22, 20, 19];
+        for (&want, got) in want.iter().zip(input.iter()) {
+            assert_eq!(want, single_pack(&got.0, &got.1));
+        }
+    }
+
+    #[test]
+    fn test_part1() {
+        let input_str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+        let input = parse_input(input_str).unwrap();
+        let want = 157;
+        let got = part1(&input);
+        assert_eq!(want, got);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input_str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+        let input = parse_input(input_str).unwrap();
+        let want = 70;
+        let got = part2(&input);
+        assert_eq!(want, got);
+    }
+}
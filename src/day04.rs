@@ -1,22 +1,25 @@
-fn parse(input_str: &str) -> Vec<(Set, Set)> {
-    input_str
-        .lines()
-        .map(|line| {
-            let (s1, s2) = line.split_once(',').expect("Could not split on ','");
-            (Set::parse(s1), Set::parse(s2))
-        })
-        .collect()
+use crate::error::{parse_lines, ParseError};
+use crate::Solution;
+
+pub fn parse(input_str: &str) -> Result<Vec<(Set, Set)>, ParseError> {
+    parse_lines(input_str, |line| {
+        let (s1, s2) = line
+            .split_once(',')
+            .ok_or_else(|| ParseError::new(Day04::DAY, line, "two ranges separated by ','"))?;
+        Ok((Set::parse(s1)?, Set::parse(s2)?))
+    })
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-struct Set(u8, u8);
+pub struct Set(u8, u8);
 
 impl Set {
-    fn parse(input_str: &str) -> Set {
-        let (lo_s, hi_s) = input_str.split_once('-').expect("Could not split on dash");
-        let lo = lo_s.parse().expect("Could not parse low u8");
-        let hi = hi_s.parse().expect("Could not parse high u8");
-        Set(lo, hi)
+    fn parse(input_str: &str) -> Result<Set, ParseError> {
+        let bad_range = || ParseError::new(Day04::DAY, input_str, "a range like '2-4'");
+        let (lo_s, hi_s) = input_str.split_once('-').ok_or_else(bad_range)?;
+        let lo = lo_s.parse().map_err(|_| bad_range())?;
+        let hi = hi_s.parse().map_err(|_| bad_range())?;
+        Ok(Set(lo, hi))
     }
 
     /// If both the low and the high of one are equal or beyond the other
@@ -35,42 +38,35 @@ impl Set {
     }
 }
 
-fn part1(input: &[(Set, Set)]) -> usize {
+pub fn part1(input: &[(Set, Set)]) -> usize {
     input
         .iter()
         .filter(|(s1, s2)| s1.either_is_proper_subset(*s2))
         .count()
 }
 
-fn part2(input: &[(Set, Set)]) -> usize {
+pub fn part2(input: &[(Set, Set)]) -> usize {
     input.iter().filter(|(s1, s2)| s1.any_overlap(*s2)).count()
 }
 
-fn main() {
-    let setup_time = std::time::Instant::now();
+pub struct Day04;
 
-    // Read the input file for day 1
-    let input_str =
-        std::fs::read_to_string("input/day04.txt").expect("Failed to read day 4 input file");
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    type Input = Vec<(Set, Set)>;
+    type Answer = usize;
 
-    // Parse the input into a vector of numbers
-    let input = parse(&input_str);
-
-    println!("Setup took {:.6} µs", setup_time.elapsed().as_micros());
-
-    // Part 1
-    let part1_time = std::time::Instant::now();
-    let part1_result = part1(&input);
-    println!("Part 1 took {:.6} ns", part1_time.elapsed().as_nanos());
+    fn parse(input_str: &str) -> Result<Self::Input, ParseError> {
+        parse(input_str)
+    }
 
-    // Part 2
-    let part2_time = std::time::Instant::now();
-    let part2_result = part2(&input);
-    println!("Part 2 took {:.6} ns", part2_time.elapsed().as_nanos());
+    fn part1(input: &Self::Input) -> Self::Answer {
+        part1(input)
+    }
 
-    println!();
-    println!("Part 1 result: {}", part1_result);
-    println!("Part 2 result: {}", part2_result);
+    fn part2(input: &Self::Input) -> Self::Answer {
+        part2(input)
+    }
 }
 
 #[cfg(test)]
@@ -93,13 +89,30 @@ mod tests {
             (Set(6, 6), Set(4, 6)),
             (Set(2, 6), Set(4, 8)),
         ];
-        let got = parse(input_str);
+        let got = parse(input_str).unwrap();
         assert_eq!(want, got);
     }
 
+    #[test]
+    fn test_parse_error() {
+        let input_str = "2-4,6-8
+2-3,4-5
+5-7,7-x";
+        let want = ParseError {
+            day: 4,
+            line: 3,
+            text: "7-x".to_string(),
+            expected: "a range like '2-4'".to_string(),
+        };
+        assert_eq!(Err(want), parse(input_str));
+
+        let got = parse("2-4;6-8").map_err(|e| e.expected);
+        assert_eq!(Err("two ranges separated by ','".to_string()), got);
+    }
+
     #[test]
     fn test_either_is_proper_subset() {
-        let input = vec![
+        let input = [
             (Set(2, 4), Set(6, 8)),
             (Set(2, 3), Set(4, 5)),
             (Set(5, 7), Set(7, 9)),
@@ -0,0 +1,424 @@
+use crate::error::ParseError;
+use crate::Solution;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Instruction {
+    number: u8,
+    from: usize,
+    to: usize,
+}
+
+impl Instruction {
+    /// Average instruction might look like
+    /// `move 11 from 9 to 3`
+    fn parse(input_str: &str) -> Result<Instruction, ParseError> {
+        let bad_instruction = || {
+            ParseError::new(
+                Day05::DAY,
+                input_str,
+                "an instruction like 'move 1 from 2 to 1'",
+            )
+        };
+
+        let words: Vec<&str> = input_str.split_ascii_whitespace().collect();
+        let [move_s, number_s, from_s, from_n_s, to_s, to_n_s] = words[..] else {
+            return Err(bad_instruction());
+        };
+        if (move_s, from_s, to_s) != ("move", "from", "to") {
+            return Err(bad_instruction());
+        }
+
+        // Gather the number
+        let number: u8 = number_s.parse().map_err(|_| bad_instruction())?;
+
+        // Gather the `from` and `to` numbers. The stacks are numbered from 1
+        let from: usize = from_n_s.parse().map_err(|_| bad_instruction())?;
+        let to: usize = to_n_s.parse().map_err(|_| bad_instruction())?;
+        if from == 0 || to == 0 {
+            return Err(bad_instruction());
+        }
+
+        Ok(Instruction {
+            number,
+            from: from - 1,
+            to: to - 1,
+        })
+    }
+}
+
+/// Input string has general form
+/// ```text
+///     [D]    
+/// [N] [C]    
+/// [Z] [M] [P]
+///  1   2   3
+///
+/// move 1 from 2 to 1
+/// move 3 from 1 to 3
+/// move 2 from 2 to 1
+/// move 1 from 1 to 2
+/// ```
+pub fn parse(input_str: &str) -> Result<(Vec<Vec<char>>, Vec<Instruction>), ParseError> {
+    // First split on the blank line, which `lines` finds whatever the line endings are
+    let lines: Vec<&str> = input_str.lines().collect();
+    let n_crate_lines = lines
+        .iter()
+        .position(|line| line.is_empty())
+        .ok_or_else(|| {
+            ParseError::new(
+                Day05::DAY,
+                "",
+                "a blank line between the crates and the instructions",
+            )
+            .at_line(lines.len() + 1)
+        })?;
+    let crate_lines = &lines[..n_crate_lines];
+    // Blank lines at the very end are not instructions
+    let n_instruction_lines = lines[n_crate_lines + 1..]
+        .iter()
+        .rposition(|line| !line.is_empty())
+        .map_or(0, |idx| idx + 1);
+    let instruction_lines = &lines[n_crate_lines + 1..][..n_instruction_lines];
+
+    // Parse the instructions. They start after the crates and the blank line
+    let instructions: Vec<Instruction> = instruction_lines
+        .iter()
+        .enumerate()
+        .map(|(idx, line)| Instruction::parse(line).map_err(|e| e.at_line(idx + n_crate_lines + 2)))
+        .collect::<Result<_, _>>()?;
+
+    // Flip the lines of crates, and skip the first one, that is just the numbers
+    let mut crate_lines = crate_lines.iter().copied().enumerate().rev();
+    // In the first row, count how many stacks there are
+    let n_stacks = match crate_lines.next() {
+        Some((_, numbers)) if !numbers.trim().is_empty() => {
+            numbers.trim().split_ascii_whitespace().count()
+        }
+        _ => {
+            return Err(ParseError::new(Day05::DAY, "", "a row of stack numbers")
+                .at_line(n_crate_lines.max(1)))
+        }
+    };
+
+    // Create the set of empty stacks
+    let mut stacks: Vec<Vec<char>> = vec![Vec::new(); n_stacks];
+
+    // Loop over the crate lines
+    for (idx, level) in crate_lines {
+        let line_chars: Vec<char> = level.chars().collect();
+        let mut crates = line_chars.chunks(4);
+
+        // Loop over each stack. A line can stop early if the stacks to its right are
+        // shorter
+        for (this_stack, possible_crate) in stacks.iter_mut().zip(&mut crates) {
+            // Get the next four characters. If they're whitespace, do nothing
+            // if they're '[', 'A', ']', ' ', then capture the character and
+            // push it onto the `stack_num` vec
+            match possible_crate {
+                ['[', c, ']', ..] => this_stack.push(*c),
+                _ if possible_crate.iter().all(|c| c.is_whitespace()) => {}
+                _ => {
+                    return Err(ParseError::new(
+                        Day05::DAY,
+                        &possible_crate.iter().collect::<String>(),
+                        "a crate like '[A]' or empty space",
+                    )
+                    .at_line(idx + 1))
+                }
+            }
+        }
+
+        // Anything left over is beyond the last stack
+        if crates.next().is_some() {
+            return Err(
+                ParseError::new(Day05::DAY, level, format!("at most {} stacks", n_stacks))
+                    .at_line(idx + 1),
+            );
+        }
+    }
+
+    // Every instruction has to move crates between stacks that exist, and never move
+    // more crates than the stack holds at the time
+    let mut heights: Vec<usize> = stacks.iter().map(Vec::len).collect();
+    for (idx, (instr, line)) in instructions.iter().zip(instruction_lines).enumerate() {
+        let bad_instruction = |expected: String| {
+            ParseError::new(Day05::DAY, line, expected).at_line(idx + n_crate_lines + 2)
+        };
+        if instr.from >= n_stacks || instr.to >= n_stacks {
+            return Err(bad_instruction(format!(
+                "stack numbers from 1 to {}",
+                n_stacks
+            )));
+        }
+
+        let number = instr.number as usize;
+        if number > heights[instr.from] {
+            return Err(bad_instruction(format!(
+                "a move of at most the {} crates on stack {}",
+                heights[instr.from],
+                instr.from + 1
+            )));
+        }
+        heights[instr.from] -= number;
+        heights[instr.to] += number;
+    }
+
+    Ok((stacks, instructions))
+}
+
+/// Each instruction tells us to move some number of crates from one stack to another
+pub fn part1(stacks: &[Vec<char>], instructions: &[Instruction]) -> String {
+    let mut s = stacks.to_owned();
+    for instr in instructions {
+        for _ in 0..instr.number {
+            let transfer_crate = s[instr.from]
+                .pop()
+                .expect("parse checks no stack runs out of crates");
+            s[instr.to].push(transfer_crate);
+        }
+    }
+
+    s.iter()
+        .map(|v| v.last().unwrap_or(&' '))
+        .copied()
+        .collect::<String>()
+}
+
+pub fn part2(stacks: &[Vec<char>], instructions: &[Instruction]) -> String {
+    let mut s = stacks.to_owned();
+    for instr in instructions {
+        // Pop the instr.number crates from instr.from into a vec, reverse it, push it to instr.to
+        let to_transfer: Vec<char> = (0..instr.number)
+            .map(|_| {
+                s[instr.from]
+                    .pop()
+                    .expect("parse checks no stack runs out of crates")
+            })
+            .collect();
+        s[instr.to].extend(to_transfer.iter().rev());
+    }
+
+    s.iter()
+        .map(|v| v.last().unwrap_or(&' '))
+        .copied()
+        .collect::<String>()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    type Input = (Vec<Vec<char>>, Vec<Instruction>);
+    type Answer = String;
+
+    fn parse(input_str: &str) -> Result<Self::Input, ParseError> {
+        parse(input_str)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer {
+        part1(&input.0, &input.1)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer {
+        part2(&input.0, &input.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_instructions_parse() {
+        let input_str = "move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2";
+        let want = vec![
+            Instruction {
+                number: 1,
+                from: 1,
+                to: 0,
+            },
+            Instruction {
+                number: 3,
+                from: 0,
+                to: 2,
+            },
+            Instruction {
+                number: 2,
+                from: 1,
+                to: 0,
+            },
+            Instruction {
+                number: 1,
+                from: 0,
+                to: 1,
+            },
+        ];
+        let got: Vec<Instruction> = input_str
+            .lines()
+            .map(|line| Instruction::parse(line).unwrap())
+            .collect();
+
+        assert_eq!(want, got);
+    }
+
+    #[test]
+    fn test_parse() {
+        let input_str = "    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2";
+        let want = (
+            vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']],
+            vec![
+                Instruction {
+                    number: 1,
+                    from: 1,
+                    to: 0,
+                },
+                Instruction {
+                    number: 3,
+                    from: 0,
+                    to: 2,
+                },
+                Instruction {
+                    number: 2,
+                    from: 1,
+                    to: 0,
+                },
+                Instruction {
+                    number: 1,
+                    from: 0,
+                    to: 1,
+                },
+            ],
+        );
+        let got = parse(input_str).unwrap();
+
+        assert_eq!(want, got);
+
+        // Windows line endings, and blank lines at the end
+        let got = parse(&(input_str.replace('\n', "\r\n") + "\r\n\r\n")).unwrap();
+        assert_eq!(want, got);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input_str = "    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 ot 1
+move 1 from 1 to 2";
+        let want = ParseError {
+            day: 5,
+            line: 8,
+            text: "move 2 from 2 ot 1".to_string(),
+            expected: "an instruction like 'move 1 from 2 to 1'".to_string(),
+        };
+        assert_eq!(Err(want), parse(input_str));
+
+        let input_str = "    [D]    
+[N] (C)    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 4";
+        let got = parse(input_str).map_err(|e| (e.line, e.text));
+        assert_eq!(Err((2, "(C) ".to_string())), got);
+
+        let input_str = "[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 4";
+        let got = parse(input_str).map_err(|e| (e.line, e.expected));
+        assert_eq!(Err((4, "stack numbers from 1 to 3".to_string())), got);
+
+        // Only one crate to move, even when it goes back where it came from
+        let got = parse("[A]\n 1 \n\nmove 2 from 1 to 1").map_err(|e| (e.line, e.expected));
+        let want = "a move of at most the 1 crates on stack 1".to_string();
+        assert_eq!(Err((4, want)), got);
+
+        // The second move empties stack 2 before the third needs it
+        let input_str = "[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 1 from 1 to 3
+move 1 from 2 to 3";
+        let got = parse(input_str).map_err(|e| e.line);
+        assert_eq!(Err(6), got);
+    }
+
+    #[test]
+    fn test_part1() {
+        let stack = vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']];
+        let instructions = vec![
+            Instruction {
+                number: 1,
+                from: 1,
+                to: 0,
+            },
+            Instruction {
+                number: 3,
+                from: 0,
+                to: 2,
+            },
+            Instruction {
+                number: 2,
+                from: 1,
+                to: 0,
+            },
+            Instruction {
+                number: 1,
+                from: 0,
+                to: 1,
+            },
+        ];
+        let want = String::from("CMZ");
+        let got = part1(&stack, &instructions);
+
+        assert_eq!(want, got);
+    }
+
+    #[test]
+    fn test_part2() {
+        let stack = vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']];
+        let instructions = vec![
+            Instruction {
+                number: 1,
+                from: 1,
+                to: 0,
+            },
+            Instruction {
+                number: 3,
+                from: 0,
+                to: 2,
+            },
+            Instruction {
+                number: 2,
+                from: 1,
+                to: 0,
+            },
+            Instruction {
+                number: 1,
+                from: 0,
+                to: 1,
+            },
+        ];
+        let want = String::from("MCD");
+        let got = part2(&stack, &instructions);
+
+        assert_eq!(want, got);
+    }
+}
@@ -1,10 +1,13 @@
 use itertools::Itertools;
 
-fn parse(input_str: &str) -> Vec<char> {
+use crate::error::ParseError;
+use crate::Solution;
+
+pub fn parse(input_str: &str) -> Vec<char> {
     input_str.trim().chars().collect()
 }
 
-fn solve(input: &[char], n_unique_chars: usize) -> usize {
+pub fn solve(input: &[char], n_unique_chars: usize) -> usize {
     for (idx, window) in input.windows(n_unique_chars).enumerate() {
         if window.iter().unique().count() == n_unique_chars {
             return idx + n_unique_chars;
@@ -13,31 +16,26 @@ fn solve(input: &[char], n_unique_chars: usize) -> usize {
     0
 }
 
-fn main() {
-    let setup_time = std::time::Instant::now();
-
-    // Read the input file for day 1
-    let input_str =
-        std::fs::read_to_string("input/day06.txt").expect("Failed to read day 6 input file");
-
-    // Parse the input into a vector of numbers
-    let input = parse(&input_str);
+pub struct Day06;
 
-    println!("Setup took {:.6} µs", setup_time.elapsed().as_micros());
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    type Input = Vec<char>;
+    type Answer = usize;
 
-    // Part 1
-    let part1_time = std::time::Instant::now();
-    let part1_result = solve(&input, 4);
-    println!("Part 1 took {:.6} µs", part1_time.elapsed().as_micros());
+    fn parse(input_str: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse(input_str))
+    }
 
-    // Part 2
-    let part2_time = std::time::Instant::now();
-    let part2_result = solve(&input, 14);
-    println!("Part 2 took {:.6} µs", part2_time.elapsed().as_micros());
+    /// Start of packet marker
+    fn part1(input: &Self::Input) -> Self::Answer {
+        solve(input, 4)
+    }
 
-    println!();
-    println!("Part 1 result: {}", part1_result);
-    println!("Part 2 result: {}", part2_result);
+    /// Start of message marker
+    fn part2(input: &Self::Input) -> Self::Answer {
+        solve(input, 14)
+    }
 }
 
 #[cfg(test)]
@@ -46,14 +44,14 @@ mod tests {
 
     #[test]
     fn test_part1() {
-        let inputs = vec![
+        let inputs = [
             "mjqjpqmgbljsphdztnvjfqwrcgsmlb".chars().collect_vec(),
             "bvwbjplbgvbhsrlpgdmjqwftvncz".chars().collect_vec(),
             "nppdvjthqldpwncqszvftbrmjlhg".chars().collect_vec(),
             "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg".chars().collect_vec(),
             "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw".chars().collect_vec(),
         ];
-        let want = vec![7, 5, 6, 10, 11];
+        let want = [7, 5, 6, 10, 11];
         for (want, got) in want.iter().zip(inputs.iter()) {
             assert_eq!(*want, solve(got, 4));
         }
@@ -61,14 +59,14 @@ mod tests {
 
     #[test]
     fn test_part2() {
-        let inputs = vec![
+        let inputs = [
             "mjqjpqmgbljsphdztnvjfqwrcgsmlb".chars().collect_vec(),
             "bvwbjplbgvbhsrlpgdmjqwftvncz".chars().collect_vec(),
             "nppdvjthqldpwncqszvftbrmjlhg".chars().collect_vec(),
             "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg".chars().collect_vec(),
             "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw".chars().collect_vec(),
         ];
-        let want = vec![19, 23, 23, 29, 26];
+        let want = [19, 23, 23, 29, 26];
         for (want, got) in want.iter().zip(inputs.iter()) {
             assert_eq!(*want, solve(got, 14));
         }
@@ -0,0 +1,1269 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::io;
+use std::ops::RangeBounds;
+use std::path::Path;
+
+use crate::error::ParseError;
+use crate::Solution;
+
+/// A nested, owned copy of part of a [`FileSystem`], that is easy to build by hand and
+/// compare
+#[derive(Debug, PartialEq, Eq)]
+pub enum Entry {
+    Dir {
+        full_path: String,
+        children: Vec<Entry>,
+    },
+    File {
+        full_path: String,
+        size: usize,
+    },
+}
+
+impl Entry {
+    pub fn full_path(&self) -> &str {
+        match self {
+            Entry::Dir { full_path, .. } | Entry::File { full_path, .. } => full_path,
+        }
+    }
+
+    /// Calculates the size of this entry.
+    /// If this is a File, then simply returns the size
+    /// If this is a Dir, then recursively calculates the size of all children
+    pub fn get_size(&self) -> usize {
+        match self {
+            Entry::Dir {
+                full_path: _,
+                children,
+            } => children.iter().map(Entry::get_size).sum(),
+            Entry::File { full_path: _, size } => *size,
+        }
+    }
+}
+
+/// How much space one file or directory takes up, for `du`-style reports
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Usage {
+    pub path: String,
+    pub size: usize,
+}
+
+impl fmt::Display for Usage {
+    /// Like a line of `du`: the size, a tab, then the path
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}\t{}", self.size, self.path)
+    }
+}
+
+/// What a [`FileSystem::plan_deletion`] tries to keep small, once it frees enough space
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Goal {
+    /// Free as little more than the target as possible
+    LeastOvershoot,
+    /// Delete as few files and directories as possible
+    FewestDeletions,
+}
+
+/// Files and directories to delete together, none of them inside another
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    /// In order of path
+    pub deleted: Vec<Usage>,
+    /// The total size of everything deleted
+    pub freed: usize,
+}
+
+/// Trees with at most this many files and directories below the root get an exact
+/// deletion plan. Bigger ones get a greedy one.
+const EXACT_PLAN_NODES: usize = 20;
+
+/// The index of a node in a [`FileSystem`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(usize);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum NodeKind {
+    Dir {
+        /// In the order they were listed
+        children: Vec<NodeId>,
+        by_name: HashMap<String, NodeId>,
+    },
+    File,
+}
+
+/// A file or directory
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Node {
+    name: String,
+    parent: Option<NodeId>,
+    /// The size of a file, or the total size of everything in a directory
+    size: usize,
+    kind: NodeKind,
+}
+
+impl Node {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The directory this is in, or None for the root
+    pub fn parent(&self) -> Option<NodeId> {
+        self.parent
+    }
+
+    /// The size of a file, or the total size of everything in a directory. Directory
+    /// sizes are kept up to date as files are added, so this never has to walk the tree.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_dir(&self) -> bool {
+        matches!(self.kind, NodeKind::Dir { .. })
+    }
+
+    /// Everything directly inside a directory, in the order it was listed. Files have
+    /// no children.
+    pub fn children(&self) -> &[NodeId] {
+        match &self.kind {
+            NodeKind::Dir { children, .. } => children,
+            NodeKind::File => &[],
+        }
+    }
+}
+
+/// Every file and directory, stored in one arena and linked by [`NodeId`]s. Each node
+/// knows its parent, and each directory finds its children by name, so moving around
+/// never has to search the tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileSystem {
+    nodes: Vec<Node>,
+}
+
+impl Default for FileSystem {
+    fn default() -> Self {
+        FileSystem::new()
+    }
+}
+
+impl FileSystem {
+    /// The root directory, `/`
+    pub const ROOT: NodeId = NodeId(0);
+
+    /// A filesystem with nothing but an empty root directory
+    pub fn new() -> FileSystem {
+        FileSystem {
+            nodes: vec![Node {
+                name: "/".to_string(),
+                parent: None,
+                size: 0,
+                kind: NodeKind::Dir {
+                    children: Vec::new(),
+                    by_name: HashMap::new(),
+                },
+            }],
+        }
+    }
+
+    /// Rebuild the filesystem from a transcript of `$ cd` and `$ ls` commands and their
+    /// output. The transcript has to start at the root with `$ cd /`. A directory that
+    /// is listed again is merged with what was listed before. An entry that both
+    /// listings have has to agree between them, but one that only the later listing
+    /// has is added.
+    pub fn parse(input_str: &str) -> Result<FileSystem, ParseError> {
+        FileSystem::parse_with_warnings(input_str).map(|(fs, _)| fs)
+    }
+
+    /// Like [`FileSystem::parse`], but also returns a warning for every `$ ls` of a
+    /// directory that had already been listed, and for every entry in such a listing
+    /// that the first one didn't have. Those listings are still merged, so nothing is
+    /// counted twice.
+    pub fn parse_with_warnings(
+        input_str: &str,
+    ) -> Result<(FileSystem, Vec<ParseError>), ParseError> {
+        let mut lines = input_str.lines().enumerate();
+        match lines.next() {
+            Some((_, "$ cd /")) => {}
+            first => {
+                let text = first.map(|(_, line)| line).unwrap_or_default();
+                return Err(ParseError::new(Day07::DAY, text, "'$ cd /'").at_line(1));
+            }
+        }
+
+        let mut fs = FileSystem::new();
+        let mut cwd = FileSystem::ROOT;
+        let mut warnings = Vec::new();
+        // Whether the lines that follow are the output of `$ ls`
+        let mut listing = false;
+        // The line the directory was first listed on, if the current `$ ls` lists it again
+        let mut relisting: Option<usize> = None;
+        // The line each directory was first listed on
+        let mut listed_at: HashMap<NodeId, usize> = HashMap::new();
+        // The line number and text that each entry was first listed with
+        let mut entry_at: HashMap<NodeId, (usize, &str)> = HashMap::new();
+        for (idx, line) in lines {
+            let bad_line =
+                |expected: &str| ParseError::new(Day07::DAY, line, expected).at_line(idx + 1);
+            let words: Vec<&str> = line.split_ascii_whitespace().collect();
+            match words[..] {
+                ["$", "ls"] => {
+                    listing = true;
+                    relisting = listed_at.get(&cwd).copied();
+                    if let Some(first) = relisting {
+                        warnings.push(bad_line(&format!(
+                            "one listing of {}, which was already listed on line {}",
+                            fs.full_path(cwd),
+                            first
+                        )));
+                    } else {
+                        listed_at.insert(cwd, idx + 1);
+                    }
+                }
+                ["$", "cd", "/"] => {
+                    listing = false;
+                    cwd = FileSystem::ROOT;
+                }
+                ["$", "cd", ".."] => {
+                    listing = false;
+                    cwd = fs[cwd]
+                        .parent
+                        .ok_or_else(|| bad_line("a directory to leave, not the root"))?;
+                }
+                ["$", "cd", name] => {
+                    listing = false;
+                    cwd = fs
+                        .child(cwd, name)
+                        .filter(|&child| fs[child].is_dir())
+                        .ok_or_else(|| bad_line("'cd' into a directory listed by 'ls'"))?;
+                }
+                ["$", ..] => return Err(bad_line("'$ cd <DIR>' or '$ ls'")),
+                _ if !listing => return Err(bad_line("'$ ls' before the entries it lists")),
+                [kind, name] => {
+                    let size = match kind {
+                        "dir" => None,
+                        size => Some(
+                            size.parse()
+                                .map_err(|_| bad_line("'dir a' or a file like '14848514 b.txt'"))?,
+                        ),
+                    };
+                    match fs.child(cwd, name) {
+                        Some(existing) => {
+                            let agrees = match fs[existing].kind {
+                                NodeKind::Dir { .. } => size.is_none(),
+                                NodeKind::File => size == Some(fs[existing].size),
+                            };
+                            if !agrees {
+                                let (first, text) = entry_at[&existing];
+                                return Err(bad_line(&format!(
+                                    "'{}', as it was listed on line {}",
+                                    text, first
+                                )));
+                            }
+                        }
+                        None => {
+                            if let Some(first) = relisting {
+                                warnings.push(bad_line(&format!(
+                                    "only entries of {} that were listed on line {}",
+                                    fs.full_path(cwd),
+                                    first
+                                )));
+                            }
+                            let id = match size {
+                                None => fs.add_dir(cwd, name),
+                                Some(size) => fs.add_file(cwd, name, size),
+                            };
+                            entry_at.insert(id, (idx + 1, line));
+                        }
+                    }
+                }
+                _ => return Err(bad_line("'dir <NAME>' or '<SIZE> <NAME>'")),
+            }
+        }
+        Ok((fs, warnings))
+    }
+
+    /// Read a real directory and everything below it from disk, with the real size of
+    /// every file. Entries are added in order of name, and symbolic links and other
+    /// special files are left out, so a link can never lead round in a loop.
+    pub fn from_dir(path: &Path) -> io::Result<FileSystem> {
+        let mut fs = FileSystem::new();
+        fs.read_dir_into(FileSystem::ROOT, path)?;
+        Ok(fs)
+    }
+
+    /// Add the contents of the directory at `path` to `dir`. Everything in a directory
+    /// is added before anything in its subdirectories, the same as a transcript would.
+    fn read_dir_into(&mut self, dir: NodeId, path: &Path) -> io::Result<()> {
+        let mut entries = std::fs::read_dir(path)?.collect::<io::Result<Vec<_>>>()?;
+        entries.sort_by_key(|entry| entry.file_name());
+
+        let mut subdirs = Vec::new();
+        for entry in entries {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            let metadata = std::fs::symlink_metadata(entry.path())?;
+            if metadata.is_dir() {
+                subdirs.push((self.add_dir(dir, &name), entry.path()));
+            } else if metadata.is_file() {
+                self.add_file(dir, &name, metadata.len() as usize);
+            }
+        }
+        for (id, subdir_path) in subdirs {
+            self.read_dir_into(id, &subdir_path)?;
+        }
+        Ok(())
+    }
+
+    /// A transcript of `$ cd` and `$ ls` commands that [`FileSystem::parse`] turns back
+    /// into this filesystem. Every directory is listed once, then visited in the order
+    /// it was listed. Names with spaces in can't be written in a transcript, so those
+    /// won't survive the trip.
+    pub fn to_transcript(&self) -> String {
+        let mut lines = vec!["$ cd /".to_string()];
+        self.write_transcript(FileSystem::ROOT, &mut lines);
+        lines.join("\n")
+    }
+
+    fn write_transcript(&self, dir: NodeId, lines: &mut Vec<String>) {
+        lines.push("$ ls".to_string());
+        for &child in self[dir].children() {
+            let node = &self[child];
+            if node.is_dir() {
+                lines.push(format!("dir {}", node.name));
+            } else {
+                lines.push(format!("{} {}", node.size, node.name));
+            }
+        }
+        for &child in self[dir].children() {
+            if self[child].is_dir() {
+                lines.push(format!("$ cd {}", self[child].name));
+                self.write_transcript(child, lines);
+                lines.push("$ cd ..".to_string());
+            }
+        }
+    }
+
+    /// The entry called `name` directly inside `dir`
+    pub fn child(&self, dir: NodeId, name: &str) -> Option<NodeId> {
+        match &self[dir].kind {
+            NodeKind::Dir { by_name, .. } => by_name.get(name).copied(),
+            NodeKind::File => None,
+        }
+    }
+
+    /// Add a node called `name` inside `dir`, unless there is one already
+    fn add_node(&mut self, dir: NodeId, name: &str, kind: NodeKind) -> NodeId {
+        if let Some(existing) = self.child(dir, name) {
+            return existing;
+        }
+
+        let id = NodeId(self.nodes.len());
+        self.nodes.push(Node {
+            name: name.to_string(),
+            parent: Some(dir),
+            size: 0,
+            kind,
+        });
+        match &mut self.nodes[dir.0].kind {
+            NodeKind::Dir { children, by_name } => {
+                children.push(id);
+                by_name.insert(name.to_string(), id);
+            }
+            NodeKind::File => panic!("{} is a file, not a directory", self.full_path(dir)),
+        }
+        id
+    }
+
+    /// Add an empty directory called `name` inside `dir`. If it is already there, the
+    /// existing one is returned.
+    pub fn add_dir(&mut self, dir: NodeId, name: &str) -> NodeId {
+        self.add_node(
+            dir,
+            name,
+            NodeKind::Dir {
+                children: Vec::new(),
+                by_name: HashMap::new(),
+            },
+        )
+    }
+
+    /// Add a file called `name` inside `dir`, and add its size to every directory above
+    /// it. If it is already there, the existing one is returned, and is not counted twice.
+    pub fn add_file(&mut self, dir: NodeId, name: &str, size: usize) -> NodeId {
+        if let Some(existing) = self.child(dir, name) {
+            return existing;
+        }
+
+        let id = self.add_node(dir, name, NodeKind::File);
+        self.nodes[id.0].size = size;
+        let mut ancestor = Some(dir);
+        while let Some(a) = ancestor {
+            self.nodes[a.0].size += size;
+            ancestor = self.nodes[a.0].parent;
+        }
+        id
+    }
+
+    /// `id` and everything below it, each directory before its children
+    pub fn walk(&self, id: NodeId) -> Vec<NodeId> {
+        let mut order = Vec::new();
+        let mut stack = vec![id];
+        while let Some(next) = stack.pop() {
+            order.push(next);
+            stack.extend(self[next].children().iter().rev());
+        }
+        order
+    }
+
+    /// The path from the root to `id`, like `/a/e/i`
+    pub fn full_path(&self, id: NodeId) -> String {
+        let mut names = Vec::new();
+        let mut node = Some(id);
+        while let Some(n) = node {
+            names.push(self[n].name());
+            node = self[n].parent;
+        }
+        // The root is already called `/`
+        names.pop();
+        names.reverse();
+        format!("/{}", names.join("/"))
+    }
+
+    /// The size of every directory, each directory before its children
+    pub fn dir_sizes(&self) -> Vec<usize> {
+        self.walk(FileSystem::ROOT)
+            .into_iter()
+            .filter(|&id| self[id].is_dir())
+            .map(|id| self[id].size)
+            .collect()
+    }
+
+    fn usage(&self, id: NodeId) -> Usage {
+        Usage {
+            path: self.full_path(id),
+            size: self[id].size,
+        }
+    }
+
+    /// Find the node at a full path like `/a/e`. A trailing `/` is allowed.
+    pub fn find(&self, path: &str) -> Option<NodeId> {
+        let relative = path.strip_prefix('/')?;
+        relative
+            .split('/')
+            .filter(|name| !name.is_empty())
+            .try_fold(FileSystem::ROOT, |dir, name| self.child(dir, name))
+    }
+
+    /// The size of the file or directory at `path`
+    pub fn path_size(&self, path: &str) -> Option<usize> {
+        self.find(path).map(|id| self[id].size)
+    }
+
+    /// Every directory, each one before its children
+    pub fn all_dirs(&self) -> Vec<Usage> {
+        self.dirs_in_range(..)
+    }
+
+    /// Every directory whose size is in `sizes`, each one before its children. For
+    /// example `..=100_000` for the small directories of part 1, or `8_000_000..` for
+    /// the ones big enough to free some space.
+    pub fn dirs_in_range(&self, sizes: impl RangeBounds<usize>) -> Vec<Usage> {
+        self.walk(FileSystem::ROOT)
+            .into_iter()
+            .filter(|&id| self[id].is_dir() && sizes.contains(&self[id].size))
+            .map(|id| self.usage(id))
+            .collect()
+    }
+
+    /// The `n` biggest files, biggest first. Files of the same size are in order of path.
+    pub fn largest_files(&self, n: usize) -> Vec<Usage> {
+        let mut files: Vec<Usage> = self
+            .walk(FileSystem::ROOT)
+            .into_iter()
+            .filter(|&id| !self[id].is_dir())
+            .map(|id| self.usage(id))
+            .collect();
+        files.sort_by(|a, b| b.size.cmp(&a.size).then_with(|| a.path.cmp(&b.path)));
+        files.truncate(n);
+        files
+    }
+
+    /// A summary like `du -d max_depth /`: every directory at most `max_depth` below the
+    /// root, each one after its children so that the root comes last
+    pub fn du(&self, max_depth: usize) -> Vec<Usage> {
+        let mut report = Vec::new();
+        self.du_below(FileSystem::ROOT, 0, max_depth, &mut report);
+        report
+    }
+
+    fn du_below(&self, dir: NodeId, depth: usize, max_depth: usize, report: &mut Vec<Usage>) {
+        if depth < max_depth {
+            for &child in self[dir].children() {
+                if self[child].is_dir() {
+                    self.du_below(child, depth + 1, max_depth, report);
+                }
+            }
+        }
+        report.push(self.usage(dir));
+    }
+
+    /// Draw the tree like the puzzle statement, one entry per line, with the total size
+    /// of each directory
+    ///
+    /// ```text
+    /// - / (dir, size=48381165)
+    ///   - a (dir, size=94853)
+    ///     - e (dir, size=584)
+    ///       - i (file, size=584)
+    /// ```
+    pub fn render_tree(&self) -> String {
+        let mut lines = Vec::new();
+        let mut stack = vec![(FileSystem::ROOT, 0)];
+        while let Some((id, depth)) = stack.pop() {
+            let node = &self[id];
+            let kind = if node.is_dir() { "dir" } else { "file" };
+            lines.push(format!(
+                "{}- {} ({}, size={})",
+                "  ".repeat(depth),
+                node.name,
+                kind,
+                node.size
+            ));
+            stack.extend(node.children().iter().rev().map(|&c| (c, depth + 1)));
+        }
+        lines.join("\n")
+    }
+
+    /// The whole tree as nested JSON. Every entry has a `name`, a `type` of `"dir"` or
+    /// `"file"`, and a `size`, and directories have their `children` in listing order.
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        self.write_json(FileSystem::ROOT, 0, &mut json);
+        json
+    }
+
+    fn write_json(&self, id: NodeId, depth: usize, json: &mut String) {
+        let node = &self[id];
+        let indent = "  ".repeat(depth);
+        let kind = if node.is_dir() { "dir" } else { "file" };
+        json.push_str(&format!(
+            "{}{{\"name\": {}, \"type\": \"{}\", \"size\": {}",
+            indent,
+            json_string(&node.name),
+            kind,
+            node.size
+        ));
+        if node.is_dir() {
+            if node.children().is_empty() {
+                json.push_str(", \"children\": []");
+            } else {
+                json.push_str(", \"children\": [\n");
+                for (idx, &child) in node.children().iter().enumerate() {
+                    if idx > 0 {
+                        json.push_str(",\n");
+                    }
+                    self.write_json(child, depth + 1, json);
+                }
+                json.push_str(&format!("\n{}]", indent));
+            }
+        }
+        json.push('}');
+    }
+
+    /// Choose files and directories to delete that free at least `target` between
+    /// them, making `goal` as small as possible and then the other one. Deleting a
+    /// directory deletes everything in it, so nothing is chosen inside something else
+    /// that is, and the root is never chosen. None if there isn't enough to delete.
+    ///
+    /// Small trees try every choice. Big trees use a greedy plan that works for both
+    /// goals but may not be the best: delete the smallest thing that is enough on its
+    /// own if there is one, and otherwise the biggest thing, then go round again.
+    pub fn plan_deletion(&self, target: usize, goal: Goal) -> Option<Plan> {
+        let mut deleted = if self.nodes.len() <= EXACT_PLAN_NODES + 1 {
+            self.plan_exact(target, goal)?
+        } else {
+            self.plan_greedy(target)?
+        };
+        deleted.sort_by_key(|&id| self.full_path(id));
+        Some(Plan {
+            freed: deleted.iter().map(|&id| self[id].size).sum(),
+            deleted: deleted.into_iter().map(|id| self.usage(id)).collect(),
+        })
+    }
+
+    fn plan_exact(&self, target: usize, goal: Goal) -> Option<Vec<NodeId>> {
+        self.deletion_options(FileSystem::ROOT, target)
+            .into_iter()
+            .filter(|&(freed, _)| freed >= target)
+            .min_by_key(|(freed, deleted)| match goal {
+                Goal::LeastOvershoot => (*freed, deleted.len()),
+                Goal::FewestDeletions => (deleted.len(), *freed),
+            })
+            .map(|(_, deleted)| deleted)
+    }
+
+    /// Every total that deleting things in `id` can free, with the fewest deletions
+    /// that free it. Either `id` itself is deleted, or any mix of what can be deleted in
+    /// each of its children.
+    fn deletion_options(&self, id: NodeId, target: usize) -> BTreeMap<usize, Vec<NodeId>> {
+        let mut options = BTreeMap::from([(0, Vec::new())]);
+        for &child in self[id].children() {
+            let child_options = self.deletion_options(child, target);
+            let mut merged: BTreeMap<usize, Vec<NodeId>> = BTreeMap::new();
+            for (freed, deleted) in &options {
+                for (more, more_deleted) in &child_options {
+                    let count = deleted.len() + more_deleted.len();
+                    let entry = merged.entry(freed + more).or_default();
+                    if entry.is_empty() || count < entry.len() {
+                        *entry = deleted.iter().chain(more_deleted).copied().collect();
+                    }
+                }
+            }
+            options = prune_options(merged, target);
+        }
+
+        let size = self[id].size;
+        let fewer = options.get(&size).is_none_or(|deleted| deleted.len() > 1);
+        if id != FileSystem::ROOT && size > 0 && fewer {
+            options.insert(size, vec![id]);
+        }
+        prune_options(options, target)
+    }
+
+    fn plan_greedy(&self, target: usize) -> Option<Vec<NodeId>> {
+        let mut candidates: Vec<NodeId> = (1..self.nodes.len())
+            .map(NodeId)
+            .filter(|&id| self[id].size > 0)
+            .collect();
+        candidates.sort_by_key(|&id| Reverse(self[id].size));
+
+        let mut deleted = Vec::new();
+        let mut freed = 0;
+        // Whether a node is inside something that will be deleted, or has something
+        // inside it that will be
+        let mut blocked = vec![false; self.nodes.len()];
+        while freed < target {
+            candidates.retain(|&id| !blocked[id.0]);
+            let still_needed = target - freed;
+            let next = candidates
+                .iter()
+                .rev()
+                .find(|&&id| self[id].size >= still_needed)
+                .or_else(|| candidates.first())
+                .copied()?;
+
+            deleted.push(next);
+            freed += self[next].size;
+            for below in self.walk(next) {
+                blocked[below.0] = true;
+            }
+            let mut ancestor = self[next].parent;
+            while let Some(a) = ancestor {
+                blocked[a.0] = true;
+                ancestor = self[a].parent;
+            }
+        }
+        Some(deleted)
+    }
+
+    /// A nested copy of `id` and everything below it
+    pub fn to_entry(&self, id: NodeId) -> Entry {
+        let full_path = self.full_path(id);
+        if self[id].is_dir() {
+            Entry::Dir {
+                full_path,
+                children: self[id]
+                    .children()
+                    .iter()
+                    .map(|&c| self.to_entry(c))
+                    .collect(),
+            }
+        } else {
+            Entry::File {
+                full_path,
+                size: self[id].size,
+            }
+        }
+    }
+}
+
+impl std::ops::Index<NodeId> for FileSystem {
+    type Output = Node;
+
+    fn index(&self, id: NodeId) -> &Node {
+        &self.nodes[id.0]
+    }
+}
+
+/// Every total below `target` can still grow into a better plan, but of the ones that
+/// reach it, only keep those that need fewer deletions than every smaller total
+fn prune_options(
+    options: BTreeMap<usize, Vec<NodeId>>,
+    target: usize,
+) -> BTreeMap<usize, Vec<NodeId>> {
+    let mut fewest = usize::MAX;
+    options
+        .into_iter()
+        .filter(|(freed, deleted)| {
+            if *freed < target {
+                return true;
+            }
+            let keep = deleted.len() < fewest;
+            fewest = fewest.min(deleted.len());
+            keep
+        })
+        .collect()
+}
+
+/// `s` as a quoted JSON string
+fn json_string(s: &str) -> String {
+    let mut quoted = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+pub fn parse(input_str: &str) -> Result<FileSystem, ParseError> {
+    FileSystem::parse(input_str)
+}
+
+/// The total size of every directory that holds at most 100000. Files in nested
+/// directories count once for each directory they are in.
+pub fn part1(fs: &FileSystem) -> usize {
+    fs.dir_sizes().into_iter().filter(|&s| s <= 100_000).sum()
+}
+
+/// The disk holds 70000000, and the update needs 30000000 free. Find the size of the
+/// smallest directory that frees enough space when it is deleted.
+pub fn part2(fs: &FileSystem) -> usize {
+    let unused = 70_000_000_usize.saturating_sub(fs[FileSystem::ROOT].size());
+    let needed = 30_000_000_usize.saturating_sub(unused);
+    fs.dir_sizes()
+        .into_iter()
+        .filter(|&s| s >= needed)
+        .min()
+        .expect("Deleting the root always frees enough space")
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    type Input = FileSystem;
+    type Answer = usize;
+
+    fn parse(input_str: &str) -> Result<Self::Input, ParseError> {
+        parse(input_str)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k";
+
+    #[test]
+    fn test_parse() {
+        let input_str = "$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k";
+        let want = Entry::Dir {
+            full_path: "/".to_string(),
+            children: vec![
+                Entry::Dir {
+                    full_path: "/a".to_string(),
+                    children: vec![
+                        Entry::Dir {
+                            full_path: "/a/e".to_string(),
+                            children: vec![Entry::File {
+                                full_path: "/a/e/i".to_string(),
+                                size: 584,
+                            }],
+                        },
+                        Entry::File {
+                            full_path: "/a/f".to_string(),
+                            size: 29116,
+                        },
+                        Entry::File {
+                            full_path: "/a/g".to_string(),
+                            size: 2557,
+                        },
+                        Entry::File {
+                            full_path: "/a/h.lst".to_string(),
+                            size: 62596,
+                        },
+                    ],
+                },
+                Entry::File {
+                    full_path: "/b.txt".to_string(),
+                    size: 14848514,
+                },
+                Entry::File {
+                    full_path: "/c.dat".to_string(),
+                    size: 8504156,
+                },
+                Entry::Dir {
+                    full_path: "/d".to_string(),
+                    children: vec![
+                        Entry::File {
+                            full_path: "/d/j".to_string(),
+                            size: 4060174,
+                        },
+                        Entry::File {
+                            full_path: "/d/d.log".to_string(),
+                            size: 8033020,
+                        },
+                        Entry::File {
+                            full_path: "/d/d.ext".to_string(),
+                            size: 5626152,
+                        },
+                        Entry::File {
+                            full_path: "/d/k".to_string(),
+                            size: 7214296,
+                        },
+                    ],
+                },
+            ],
+        };
+        let fs = parse(input_str).unwrap();
+        let got = fs.to_entry(FileSystem::ROOT);
+        assert_eq!(want, got);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let got = parse("$ ls\n14848514 b.txt").map_err(|e| e.line);
+        assert_eq!(Err(1), got);
+
+        let input_str = "$ cd /
+$ ls
+dir a
+$ cd b";
+        let want = ParseError {
+            day: 7,
+            line: 4,
+            text: "$ cd b".to_string(),
+            expected: "'cd' into a directory listed by 'ls'".to_string(),
+        };
+        assert_eq!(Err(want), parse(input_str));
+
+        let got = parse("$ cd /\n$ ls\nbig b.txt").map_err(|e| (e.line, e.text));
+        assert_eq!(Err((3, "big b.txt".to_string())), got);
+
+        let got = parse("$ cd /\n$ rm -rf a").map_err(|e| e.line);
+        assert_eq!(Err(2), got);
+
+        let got = parse("$ cd /\n$ cd ..").map_err(|e| e.line);
+        assert_eq!(Err(2), got);
+    }
+
+    #[test]
+    fn test_validation() {
+        let errors = [
+            ("$ cd /\n$ ls\ndir a\n$ cd a\n$ cd ..\n$ cd ..", 6),
+            ("$ cd /\n$ ls\ndir a\n$ cd a\n$ cd e", 5),
+            ("$ cd /\n$ ls\n12 b.txt\n$ ls\n13 b.txt", 5),
+            ("$ cd /\n$ ls\n12 b\n$ ls\ndir b", 5),
+            ("$ cd /\n$ ls\ndir a\n$ ls\n12 a", 5),
+            ("$ cd /\n$ ls\n$ pwd", 3),
+            ("$ cd /\n$ ls -l", 2),
+            ("$ cd /\n$ ls\ndir a\n$ cd a\n12 b.txt", 5),
+            ("$ cd /\n$ ls\n12 b.txt extra", 3),
+        ];
+        for (input_str, line) in errors {
+            assert_eq!(
+                Err(line),
+                parse(input_str).map_err(|e| e.line),
+                "{}",
+                input_str
+            );
+        }
+
+        let want = ParseError {
+            day: 7,
+            line: 5,
+            text: "13 b.txt".to_string(),
+            expected: "'12 b.txt', as it was listed on line 3".to_string(),
+        };
+        assert_eq!(Err(want), parse("$ cd /\n$ ls\n12 b.txt\n$ ls\n13 b.txt"));
+    }
+
+    #[test]
+    fn test_parse_with_warnings() {
+        let (fs, warnings) = FileSystem::parse_with_warnings(EXAMPLE).unwrap();
+        assert_eq!(parse(EXAMPLE).unwrap(), fs);
+        assert!(warnings.is_empty());
+
+        // Listings that agree are merged, and each one after the first is reported, along
+        // with every entry the first listing didn't have
+        let relisted = format!(
+            "{}\n$ cd /\n$ ls\n14848514 b.txt\n$ cd a\n$ ls\n$ ls\n12 new.txt",
+            EXAMPLE
+        );
+        let (got, warnings) = FileSystem::parse_with_warnings(&relisted).unwrap();
+        let a = got.child(FileSystem::ROOT, "a").unwrap();
+        assert_eq!(Some(12), got.child(a, "new.txt").map(|id| got[id].size()));
+        assert_eq!(
+            fs[FileSystem::ROOT].size() + 12,
+            got[FileSystem::ROOT].size()
+        );
+        let got: Vec<(usize, &str)> = warnings
+            .iter()
+            .map(|w| (w.line, w.expected.as_str()))
+            .collect();
+        let want = vec![
+            (25, "one listing of /, which was already listed on line 2"),
+            (28, "one listing of /a, which was already listed on line 8"),
+            (29, "one listing of /a, which was already listed on line 8"),
+            (30, "only entries of /a that were listed on line 8"),
+        ];
+        assert_eq!(want, got);
+    }
+
+    #[test]
+    fn test_navigation() {
+        let fs = parse(EXAMPLE).unwrap();
+        let a = fs.child(FileSystem::ROOT, "a").unwrap();
+        let e = fs.child(a, "e").unwrap();
+        let i = fs.child(e, "i").unwrap();
+        assert_eq!("/a/e/i", fs.full_path(i));
+        assert_eq!("/", fs.full_path(FileSystem::ROOT));
+        assert_eq!(Some(e), fs[i].parent());
+        assert_eq!(Some(a), fs[e].parent());
+        assert_eq!(None, fs[FileSystem::ROOT].parent());
+        assert_eq!(None, fs.child(a, "i"));
+        assert_eq!(None, fs.child(i, "anything"));
+    }
+
+    #[test]
+    fn test_dir_sizes() {
+        let fs = parse(EXAMPLE).unwrap();
+        assert_eq!(48381165, fs[FileSystem::ROOT].size());
+        assert_eq!(48381165, fs.to_entry(FileSystem::ROOT).get_size());
+        assert_eq!(vec![48381165, 94853, 584, 24933642], fs.dir_sizes());
+
+        // Listing a directory again does not count its files twice
+        let relisted = format!("{}\n$ cd /\n$ ls\n14848514 b.txt\ndir a", EXAMPLE);
+        assert_eq!(fs, parse(&relisted).unwrap());
+    }
+
+    fn usage(path: &str, size: usize) -> Usage {
+        Usage {
+            path: path.to_string(),
+            size,
+        }
+    }
+
+    #[test]
+    fn test_path_size() {
+        let fs = parse(EXAMPLE).unwrap();
+        assert_eq!(Some(48381165), fs.path_size("/"));
+        assert_eq!(Some(94853), fs.path_size("/a"));
+        assert_eq!(Some(584), fs.path_size("/a/e/"));
+        assert_eq!(Some(8033020), fs.path_size("/d/d.log"));
+        assert_eq!(None, fs.path_size("/a/i"));
+        assert_eq!(None, fs.path_size("a"));
+    }
+
+    #[test]
+    fn test_dirs_in_range() {
+        let fs = parse(EXAMPLE).unwrap();
+        let want = vec![
+            usage("/", 48381165),
+            usage("/a", 94853),
+            usage("/a/e", 584),
+            usage("/d", 24933642),
+        ];
+        assert_eq!(want, fs.all_dirs());
+
+        let want = vec![usage("/a", 94853), usage("/a/e", 584)];
+        assert_eq!(want, fs.dirs_in_range(..=100_000));
+
+        let want = vec![usage("/a", 94853)];
+        assert_eq!(want, fs.dirs_in_range(1000..100_000));
+    }
+
+    #[test]
+    fn test_largest_files() {
+        let fs = parse(EXAMPLE).unwrap();
+        let want = vec![
+            usage("/b.txt", 14848514),
+            usage("/c.dat", 8504156),
+            usage("/d/d.log", 8033020),
+        ];
+        assert_eq!(want, fs.largest_files(3));
+        assert_eq!(10, fs.largest_files(100).len());
+    }
+
+    #[test]
+    fn test_du() {
+        let fs = parse(EXAMPLE).unwrap();
+        let want = vec![
+            usage("/a/e", 584),
+            usage("/a", 94853),
+            usage("/d", 24933642),
+            usage("/", 48381165),
+        ];
+        assert_eq!(want, fs.du(2));
+
+        let want = vec![
+            usage("/a", 94853),
+            usage("/d", 24933642),
+            usage("/", 48381165),
+        ];
+        assert_eq!(want, fs.du(1));
+        assert_eq!(vec![usage("/", 48381165)], fs.du(0));
+        assert_eq!("584\t/a/e", fs.du(2)[0].to_string());
+    }
+
+    #[test]
+    fn test_render_tree() {
+        let fs = parse(EXAMPLE).unwrap();
+        let want = "- / (dir, size=48381165)
+  - a (dir, size=94853)
+    - e (dir, size=584)
+      - i (file, size=584)
+    - f (file, size=29116)
+    - g (file, size=2557)
+    - h.lst (file, size=62596)
+  - b.txt (file, size=14848514)
+  - c.dat (file, size=8504156)
+  - d (dir, size=24933642)
+    - j (file, size=4060174)
+    - d.log (file, size=8033020)
+    - d.ext (file, size=5626152)
+    - k (file, size=7214296)";
+        assert_eq!(want, fs.render_tree());
+    }
+
+    #[test]
+    fn test_to_json() {
+        let fs = parse("$ cd /\n$ ls\ndir a\n14848514 b.txt\ndir d\n$ cd a\n$ ls\n584 i").unwrap();
+        let want = r#"{"name": "/", "type": "dir", "size": 14849098, "children": [
+  {"name": "a", "type": "dir", "size": 584, "children": [
+    {"name": "i", "type": "file", "size": 584}
+  ]},
+  {"name": "b.txt", "type": "file", "size": 14848514},
+  {"name": "d", "type": "dir", "size": 0, "children": []}
+]}"#;
+        assert_eq!(want, fs.to_json());
+        assert_eq!(r#""say \"hi\"\\\u0009""#, json_string("say \"hi\"\\\t"));
+    }
+
+    #[test]
+    fn test_transcript() {
+        // The example visits `a` and `d` in a different order to the one it lists them,
+        // so only the tree survives, not the order of the nodes
+        let fs = parse(EXAMPLE).unwrap();
+        let got = parse(&fs.to_transcript()).unwrap();
+        assert_eq!(
+            fs.to_entry(FileSystem::ROOT),
+            got.to_entry(FileSystem::ROOT)
+        );
+        assert_eq!(got, parse(&got.to_transcript()).unwrap());
+    }
+
+    #[test]
+    fn test_from_dir() {
+        let root = std::env::temp_dir().join(format!("aoc_2022_day07_{}", std::process::id()));
+        std::fs::create_dir_all(root.join("a").join("e")).unwrap();
+        std::fs::create_dir_all(root.join("d")).unwrap();
+        std::fs::write(root.join("b.txt"), "hello").unwrap();
+        std::fs::write(root.join("a").join("f"), "fff").unwrap();
+        std::fs::write(root.join("a").join("e").join("i"), [0; 584]).unwrap();
+
+        let fs = FileSystem::from_dir(&root).unwrap();
+        std::fs::remove_dir_all(&root).unwrap();
+
+        let want = "- / (dir, size=592)
+  - a (dir, size=587)
+    - e (dir, size=584)
+      - i (file, size=584)
+    - f (file, size=3)
+  - b.txt (file, size=5)
+  - d (dir, size=0)";
+        assert_eq!(want, fs.render_tree());
+        assert_eq!(fs, parse(&fs.to_transcript()).unwrap());
+        assert!(FileSystem::from_dir(&root).is_err());
+    }
+
+    /// Check that `plan` frees `target` with nothing deleted twice, and return how much
+    /// it frees and how many things it deletes
+    fn check_plan(fs: &FileSystem, plan: &Plan, target: usize) -> (usize, usize) {
+        assert!(plan.freed >= target);
+        assert_eq!(
+            plan.freed,
+            plan.deleted.iter().map(|u| u.size).sum::<usize>()
+        );
+        for a in &plan.deleted {
+            assert_eq!(Some(a.size), fs.path_size(&a.path));
+            for b in &plan.deleted {
+                assert!(a == b || !b.path.starts_with(&format!("{}/", a.path)));
+            }
+        }
+        (plan.freed, plan.deleted.len())
+    }
+
+    #[test]
+    fn test_plan_deletion() {
+        let fs = parse(EXAMPLE).unwrap();
+        let usage = |path: &str| Usage {
+            path: path.to_string(),
+            size: fs.path_size(path).unwrap(),
+        };
+
+        // Part 2 needs 8381165, which one file frees on its own
+        let want = Plan {
+            deleted: vec![usage("/c.dat")],
+            freed: 8504156,
+        };
+        assert_eq!(Some(want), fs.plan_deletion(8381165, Goal::FewestDeletions));
+
+        // Two files in /d get closer
+        let want = Plan {
+            deleted: vec![usage("/d/d.ext"), usage("/d/j")],
+            freed: 9686326,
+        };
+        assert_eq!(
+            Some(want),
+            fs.plan_deletion(9_000_000, Goal::LeastOvershoot)
+        );
+        let want = Plan {
+            deleted: vec![usage("/d/d.log"), usage("/d/j")],
+            freed: 12093194,
+        };
+        assert_eq!(
+            Some(want),
+            fs.plan_deletion(12_000_000, Goal::LeastOvershoot)
+        );
+
+        assert_eq!(0, fs.plan_deletion(0, Goal::LeastOvershoot).unwrap().freed);
+        assert!(fs.plan_deletion(48381165, Goal::FewestDeletions).is_some());
+        assert_eq!(None, fs.plan_deletion(48381166, Goal::FewestDeletions));
+    }
+
+    #[test]
+    fn test_plan_exact() {
+        // Compare with every set of non-nested nodes in the example
+        let fs = parse(EXAMPLE).unwrap();
+        let nodes: Vec<NodeId> = (1..fs.nodes.len()).map(NodeId).collect();
+        let mut choices = Vec::new();
+        for mask in 0..1_usize << nodes.len() {
+            let chosen: Vec<NodeId> = (0..nodes.len())
+                .filter(|bit| mask & 1 << bit != 0)
+                .map(|bit| nodes[bit])
+                .collect();
+            let nested = chosen
+                .iter()
+                .any(|&a| chosen.iter().any(|&b| a != b && fs.walk(a).contains(&b)));
+            if !nested {
+                let freed: usize = chosen.iter().map(|&id| fs[id].size()).sum();
+                choices.push((freed, chosen.len()));
+            }
+        }
+
+        for target in (0..=48381165).step_by(1_234_567) {
+            let reaching = choices.iter().filter(|&&(freed, _)| freed >= target);
+            let want = reaching.clone().min().copied();
+            let got = fs.plan_deletion(target, Goal::LeastOvershoot);
+            assert_eq!(want, got.map(|plan| check_plan(&fs, &plan, target)));
+
+            let want = reaching.map(|&(freed, count)| (count, freed)).min();
+            let got = fs.plan_deletion(target, Goal::FewestDeletions);
+            let got = got.map(|plan| check_plan(&fs, &plan, target));
+            assert_eq!(want, got.map(|(freed, count)| (count, freed)));
+        }
+    }
+
+    #[test]
+    fn test_plan_greedy() {
+        let fs = parse(&crate::gen::generate(7, 3, 200).unwrap()).unwrap();
+        let total = fs[FileSystem::ROOT].size();
+        for target in [1, total / 10, total / 2, total] {
+            let plan = fs.plan_deletion(target, Goal::LeastOvershoot).unwrap();
+            check_plan(&fs, &plan, target);
+        }
+        assert_eq!(None, fs.plan_deletion(total + 1, Goal::LeastOvershoot));
+
+        // When one thing is enough, it is the smallest of those
+        let needed = fs.dir_sizes()[1];
+        let plan = fs.plan_deletion(needed, Goal::FewestDeletions).unwrap();
+        assert_eq!(1, plan.deleted.len());
+        let smallest = fs.walk(FileSystem::ROOT)[1..]
+            .iter()
+            .map(|&id| fs[id].size())
+            .filter(|&size| size >= needed)
+            .min();
+        assert_eq!(smallest, Some(plan.freed));
+    }
+
+    #[test]
+    fn test_part1() {
+        let fs = parse(EXAMPLE).unwrap();
+        assert_eq!(95437, part1(&fs));
+    }
+
+    #[test]
+    fn test_part2() {
+        let fs = parse(EXAMPLE).unwrap();
+        assert_eq!(24933642, part2(&fs));
+    }
+}
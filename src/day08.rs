@@ -0,0 +1,1148 @@
+use ndarray::{Array2, ArrayView2};
+use std::cmp::{Ordering, Reverse};
+use std::collections::BTreeMap;
+use std::io;
+use std::path::Path;
+
+use crate::error::ParseError;
+use crate::geom::Dir;
+use crate::grid::{ray_within, Grid};
+use crate::Solution;
+
+/// Read the map of tree heights, one digit per tree. An input with no characters at all
+/// is an empty forest.
+pub fn parse(input_str: &str) -> Result<Array2<u8>, ParseError> {
+    if input_str.is_empty() {
+        return Ok(Array2::zeros((0, 0)));
+    }
+    let grid = Grid::parse(Day08::DAY, input_str, "a digit", |c| {
+        c.to_digit(10).map(|d| d as u8)
+    })?;
+    Ok(grid.into())
+}
+
+/// True if all the items directly above the idx are less than it
+pub fn visible_from_top(arr: ArrayView2<u8>, idx: (usize, usize)) -> bool {
+    (0..idx.0)
+        .map(|ridx| (ridx, idx.1))
+        .all(|new_idx| arr[idx] > arr[new_idx])
+}
+
+/// True if all the items directly to the left of the idx are less than it
+pub fn visible_from_left(arr: ArrayView2<u8>, idx: (usize, usize)) -> bool {
+    (0..idx.1)
+        .map(|cidx| (idx.0, cidx))
+        .all(|new_idx| arr[idx] > arr[new_idx])
+}
+
+/// True if all the items directly to the right of the idx are less than it
+pub fn visible_from_right(arr: ArrayView2<u8>, idx: (usize, usize)) -> bool {
+    if idx.1 == (arr.ncols() - 1) {
+        return true;
+    }
+    ((idx.1 + 1)..arr.ncols())
+        .map(|cidx| (idx.0, cidx))
+        .all(|new_idx| arr[idx] > arr[new_idx])
+}
+
+/// True if all the items directly below the idx are less than it
+pub fn visible_from_bottom(arr: ArrayView2<u8>, idx: (usize, usize)) -> bool {
+    if idx.0 == (arr.nrows() - 1) {
+        return true;
+    }
+
+    ((idx.0 + 1)..arr.nrows())
+        .map(|ridx| (ridx, idx.1))
+        .all(|new_idx| arr[idx] > arr[new_idx])
+}
+
+/// True if the tree at idx can be seen from any edge. This checks every tree between
+/// it and each edge, so it is only used to test [`visibility_map`] against.
+pub fn is_visible(arr: ArrayView2<u8>, idx: (usize, usize)) -> bool {
+    visible_from_top(arr, idx)
+        || visible_from_bottom(arr, idx)
+        || visible_from_left(arr, idx)
+        || visible_from_right(arr, idx)
+}
+
+/// Mark the trees along `line` that can be seen from its first index, which are the
+/// ones taller than every tree before them
+fn mark_visible(
+    arr: ArrayView2<u8>,
+    visible: &mut Array2<bool>,
+    line: impl Iterator<Item = (usize, usize)>,
+) {
+    let mut tallest = None;
+    for idx in line {
+        if Some(arr[idx]) > tallest {
+            visible[idx] = true;
+            tallest = Some(arr[idx]);
+            // Nothing behind the tallest possible tree can be seen
+            if arr[idx] == 9 {
+                break;
+            }
+        }
+    }
+}
+
+/// Whether each tree can be seen from outside the forest. Every row and column is swept
+/// from both ends, keeping the tallest tree so far, so each tree is looked at at most
+/// four times.
+pub fn visibility_map(arr: ArrayView2<u8>) -> Array2<bool> {
+    let (nrows, ncols) = arr.dim();
+    let mut visible = Array2::from_elem((nrows, ncols), false);
+    for ridx in 0..nrows {
+        mark_visible(arr, &mut visible, (0..ncols).map(|cidx| (ridx, cidx)));
+        mark_visible(arr, &mut visible, (0..ncols).rev().map(|cidx| (ridx, cidx)));
+    }
+    for cidx in 0..ncols {
+        mark_visible(arr, &mut visible, (0..nrows).map(|ridx| (ridx, cidx)));
+        mark_visible(arr, &mut visible, (0..nrows).rev().map(|ridx| (ridx, cidx)));
+    }
+    visible
+}
+
+pub fn part1(arr: ArrayView2<u8>) -> usize {
+    visibility_map(arr).iter().filter(|&&v| v).count()
+}
+
+/// What are the indices in the array that are directly above this `idx`
+pub fn inds_above(idx: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+    (0..idx.0).rev().map(move |ridx| (ridx, idx.1))
+}
+
+/// What are the indices in the array that are directly to the left of this `idx`
+pub fn inds_left(idx: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+    (0..idx.1).rev().map(move |cidx| (idx.0, cidx))
+}
+
+/// What are the indices in the array that are directly below this idx
+pub fn inds_below(
+    arr: ArrayView2<u8>,
+    idx: (usize, usize),
+) -> impl Iterator<Item = (usize, usize)> {
+    let last_idx = arr.nrows() - 1;
+    ((idx.0 + 1)..=last_idx).map(move |ridx| (ridx, idx.1))
+}
+
+pub fn inds_right(
+    arr: ArrayView2<u8>,
+    idx: (usize, usize),
+) -> impl Iterator<Item = (usize, usize)> {
+    let last_idx = arr.ncols() - 1;
+    ((idx.1 + 1)..=last_idx).map(move |cidx| (idx.0, cidx))
+}
+
+pub fn count_visible_trees(
+    arr: ArrayView2<u8>,
+    viewer: (usize, usize),
+    inds: &[(usize, usize)],
+) -> usize {
+    let mut score = 0;
+    for idx in inds {
+        match arr[*idx].cmp(&arr[viewer]) {
+            Ordering::Less => {
+                score += 1;
+            }
+            Ordering::Equal | Ordering::Greater => {
+                score += 1;
+                break;
+            }
+        }
+    }
+    score
+}
+
+/// A tree's scenic score is found by multiplying together its viewing distance in each
+/// of the four directions.
+pub fn get_scenic_score(arr: ArrayView2<u8>, idx: (usize, usize)) -> usize {
+    // If any returned iterator is empty, return 0
+    let left_inds: Vec<_> = inds_left(idx).collect();
+    if left_inds.is_empty() {
+        return 0;
+    }
+    let left_score = count_visible_trees(arr, idx, &left_inds);
+
+    let right_inds: Vec<_> = inds_right(arr, idx).collect();
+    if right_inds.is_empty() {
+        return 0;
+    }
+    let right_score = count_visible_trees(arr, idx, &right_inds);
+
+    let above_inds: Vec<_> = inds_above(idx).collect();
+    if above_inds.is_empty() {
+        return 0;
+    }
+    let above_score = count_visible_trees(arr, idx, &above_inds);
+
+    let below_inds: Vec<_> = inds_below(arr, idx).collect();
+    if below_inds.is_empty() {
+        return 0;
+    }
+    let below_score = count_visible_trees(arr, idx, &below_inds);
+
+    left_score * right_score * above_score * below_score
+}
+
+/// Find how far every tree along `line` can see back towards the start of the line,
+/// and pass each tree and its distance to `record`. The stack holds the trees that could
+/// still block the view of a later tree, tallest at the bottom, so each tree is pushed
+/// and popped at most once.
+fn sweep_viewing_distances(
+    arr: ArrayView2<u8>,
+    line: impl Iterator<Item = (usize, usize)>,
+    mut record: impl FnMut((usize, usize), usize),
+) {
+    // (position along the line, height)
+    let mut stack: Vec<(usize, u8)> = Vec::new();
+    for (pos, idx) in line.enumerate() {
+        let height = arr[idx];
+        while matches!(stack.last(), Some(&(_, h)) if h < height) {
+            stack.pop();
+        }
+        // The view stops at the first tree at least as tall, or else at the edge
+        let distance = match stack.last() {
+            Some(&(blocker, _)) => pos - blocker,
+            None => pos,
+        };
+        record(idx, distance);
+        stack.push((pos, height));
+    }
+}
+
+/// The scenic score of every tree, found by sweeping each row and column from both ends
+pub fn scenic_scores(arr: ArrayView2<u8>) -> Array2<usize> {
+    let (nrows, ncols) = arr.dim();
+    let mut scores = Array2::from_elem((nrows, ncols), 1);
+    let mut multiply = |idx: (usize, usize), distance| scores[idx] *= distance;
+    for ridx in 0..nrows {
+        let row = (0..ncols).map(|cidx| (ridx, cidx));
+        sweep_viewing_distances(arr, row.clone(), &mut multiply);
+        sweep_viewing_distances(arr, row.rev(), &mut multiply);
+    }
+    for cidx in 0..ncols {
+        let column = (0..nrows).map(|ridx| (ridx, cidx));
+        sweep_viewing_distances(arr, column.clone(), &mut multiply);
+        sweep_viewing_distances(arr, column.rev(), &mut multiply);
+    }
+    scores
+}
+
+/// The trees between `idx` and the edge in direction `dir`, nearest first
+pub fn inds_towards(arr: ArrayView2<u8>, idx: (usize, usize), dir: Dir) -> Vec<(usize, usize)> {
+    match dir {
+        Dir::Up => inds_above(idx).collect(),
+        Dir::Right => inds_right(arr, idx).collect(),
+        Dir::Down => inds_below(arr, idx).collect(),
+        Dir::Left => inds_left(idx).collect(),
+    }
+}
+
+/// How many trees can be seen from the tree at `idx` looking in direction `dir`
+pub fn viewing_distance(arr: ArrayView2<u8>, idx: (usize, usize), dir: Dir) -> usize {
+    count_visible_trees(arr, idx, &inds_towards(arr, idx, dir))
+}
+
+/// The viewing distance from the tree at `idx` in each direction of [`Dir::ALL`], so
+/// up, right, down then left. Their product is the scenic score.
+pub fn viewing_distances(arr: ArrayView2<u8>, idx: (usize, usize)) -> [usize; 4] {
+    Dir::ALL.map(|dir| viewing_distance(arr, idx, dir))
+}
+
+/// The `k` trees with the best scenic scores, best first, with their scores. Trees with
+/// the same score are in reading order.
+pub fn top_scenic_trees(arr: ArrayView2<u8>, k: usize) -> Vec<((usize, usize), usize)> {
+    let mut trees: Vec<(Reverse<usize>, (usize, usize))> = scenic_scores(arr)
+        .indexed_iter()
+        .map(|(idx, &score)| (Reverse(score), idx))
+        .collect();
+    // Only the best k need sorting
+    if k < trees.len() {
+        trees.select_nth_unstable(k);
+        trees.truncate(k);
+    }
+    trees.sort_unstable();
+    trees
+        .into_iter()
+        .map(|(Reverse(score), idx)| (idx, score))
+        .collect()
+}
+
+/// Whether each tree can be seen from outside the forest beyond the edge in direction
+/// `edge`. [`Dir::Up`] is the top edge, so the trees are looked at down each column.
+pub fn visible_from_edge(arr: ArrayView2<u8>, edge: Dir) -> Array2<bool> {
+    let (nrows, ncols) = arr.dim();
+    let mut visible = Array2::from_elem((nrows, ncols), false);
+    match edge {
+        Dir::Up | Dir::Down => {
+            for cidx in 0..ncols {
+                let line = (0..nrows).map(move |ridx| (ridx, cidx));
+                if edge == Dir::Up {
+                    mark_visible(arr, &mut visible, line);
+                } else {
+                    mark_visible(arr, &mut visible, line.rev());
+                }
+            }
+        }
+        Dir::Left | Dir::Right => {
+            for ridx in 0..nrows {
+                let line = (0..ncols).map(move |cidx| (ridx, cidx));
+                if edge == Dir::Left {
+                    mark_visible(arr, &mut visible, line);
+                } else {
+                    mark_visible(arr, &mut visible, line.rev());
+                }
+            }
+        }
+    }
+    visible
+}
+
+/// The eight moves of a chess knight, clockwise from up and to the right
+pub const KNIGHT: &[(isize, isize)] = &[
+    (-2, 1),
+    (-1, 2),
+    (1, 2),
+    (2, 1),
+    (2, -1),
+    (1, -2),
+    (-1, -2),
+    (-2, -1),
+];
+
+/// True if the tree at `idx` can be seen from outside the forest along any of the rays
+/// in `steps`
+pub fn is_visible_along(
+    arr: ArrayView2<u8>,
+    idx: (usize, usize),
+    steps: &[(isize, isize)],
+) -> bool {
+    steps
+        .iter()
+        .any(|&step| ray_within(arr.dim(), idx, step).all(|other| arr[other] < arr[idx]))
+}
+
+/// The product of the viewing distances from the tree at `idx` along each of the rays in
+/// `steps`
+pub fn scenic_score_along(
+    arr: ArrayView2<u8>,
+    idx: (usize, usize),
+    steps: &[(isize, isize)],
+) -> usize {
+    steps
+        .iter()
+        .map(|&step| {
+            let inds: Vec<_> = ray_within(arr.dim(), idx, step).collect();
+            count_visible_trees(arr, idx, &inds)
+        })
+        .product()
+}
+
+/// [`part1`] looking along `steps` instead
+pub fn count_visible_along(arr: ArrayView2<u8>, steps: &[(isize, isize)]) -> usize {
+    arr.indexed_iter()
+        .filter(|&(idx, _)| is_visible_along(arr, idx, steps))
+        .count()
+}
+
+/// [`part2`] looking along `steps` instead
+pub fn best_scenic_score_along(arr: ArrayView2<u8>, steps: &[(isize, isize)]) -> usize {
+    arr.indexed_iter()
+        .map(|(idx, _)| scenic_score_along(arr, idx, steps))
+        .max()
+        .unwrap_or_default()
+}
+
+/// Find the maximum scenic score
+pub fn part2(arr: ArrayView2<u8>) -> usize {
+    // An empty forest has no trees to score
+    scenic_scores(arr).iter().copied().max().unwrap_or_default()
+}
+
+/// What a heatmap of the forest shows
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Layer {
+    /// The height of each tree
+    Heights,
+    /// Bright for the trees that can be seen from outside, dark for the others
+    Visibility,
+    /// The scenic score of each tree, scaled so the best is the brightest
+    ScenicScores,
+}
+
+/// The tree with the best scenic score, or None in an empty forest. Ties go to the
+/// first one in reading order.
+pub fn best_tree(arr: ArrayView2<u8>) -> Option<(usize, usize)> {
+    let scores = scenic_scores(arr);
+    let mut best = None;
+    for (idx, &score) in scores.indexed_iter() {
+        if best.is_none_or(|b| score > scores[b]) {
+            best = Some(idx);
+        }
+    }
+    best
+}
+
+/// How bright each tree is on a heatmap of `layer`, from 0 to 255
+pub fn heatmap(arr: ArrayView2<u8>, layer: Layer) -> Array2<u8> {
+    let scale = |value: usize, max: usize| (value * 255 / max.max(1)) as u8;
+    match layer {
+        Layer::Heights => arr.map(|&h| scale(h as usize, 9)),
+        Layer::Visibility => visibility_map(arr).map(|&v| if v { 255 } else { 0 }),
+        Layer::ScenicScores => {
+            let scores = scenic_scores(arr);
+            let max = scores.iter().copied().max().unwrap_or_default();
+            scores.map(|&s| scale(s, max))
+        }
+    }
+}
+
+/// The colour of a brightness, from dark purple through teal to yellow
+fn colour(level: u8) -> [u8; 3] {
+    const STOPS: [[u8; 3]; 3] = [[68, 1, 84], [33, 145, 140], [253, 231, 37]];
+    let (from, to, t) = if level < 128 {
+        (STOPS[0], STOPS[1], level as i32 * 2)
+    } else {
+        (STOPS[1], STOPS[2], (level as i32 - 128) * 2 + 1)
+    };
+    let mix = |a: u8, b: u8| (a as i32 + (b as i32 - a as i32) * t / 255) as u8;
+    [
+        mix(from[0], to[0]),
+        mix(from[1], to[1]),
+        mix(from[2], to[2]),
+    ]
+}
+
+/// The colour the highlighted tree is drawn in
+const HIGHLIGHT: [u8; 3] = [255, 0, 0];
+
+/// A binary greyscale PGM image with one pixel per tree. Greyscale has no colour to
+/// spare, so nothing is highlighted.
+pub fn to_pgm(levels: ArrayView2<u8>) -> Vec<u8> {
+    let mut image = format!("P5\n{} {}\n255\n", levels.ncols(), levels.nrows()).into_bytes();
+    image.extend(levels.iter());
+    image
+}
+
+/// A binary colour PPM image with one pixel per tree, with the tree at `highlight` in
+/// red
+pub fn to_ppm(levels: ArrayView2<u8>, highlight: Option<(usize, usize)>) -> Vec<u8> {
+    let mut image = format!("P6\n{} {}\n255\n", levels.ncols(), levels.nrows()).into_bytes();
+    for (idx, &level) in levels.indexed_iter() {
+        if Some(idx) == highlight {
+            image.extend(HIGHLIGHT);
+        } else {
+            image.extend(colour(level));
+        }
+    }
+    image
+}
+
+/// The heatmap drawn in a terminal with 24-bit colour, two characters wide per tree so
+/// that they come out roughly square. The tree at `highlight` is red and marked with
+/// `**`. This is only readable for grids that fit on the screen.
+pub fn to_ansi(levels: ArrayView2<u8>, highlight: Option<(usize, usize)>) -> String {
+    let mut out = String::new();
+    for (ridx, row) in levels.outer_iter().enumerate() {
+        for (cidx, &level) in row.iter().enumerate() {
+            let (rgb, text) = if Some((ridx, cidx)) == highlight {
+                (HIGHLIGHT, "**")
+            } else {
+                (colour(level), "  ")
+            };
+            out.push_str(&format!(
+                "\x1b[48;2;{};{};{}m{}",
+                rgb[0], rgb[1], rgb[2], text
+            ));
+        }
+        out.push_str("\x1b[0m\n");
+    }
+    out
+}
+
+/// Write a heatmap of `layer` to `path`, as a PGM image if it ends in `.pgm` or as a PPM
+/// image with the best tree highlighted if it ends in `.ppm`
+pub fn save_heatmap(arr: ArrayView2<u8>, layer: Layer, path: &Path) -> io::Result<()> {
+    let levels = heatmap(arr, layer);
+    let image = match path.extension().and_then(|e| e.to_str()) {
+        Some("pgm") => to_pgm(levels.view()),
+        Some("ppm") => to_ppm(levels.view(), best_tree(arr)),
+        _ => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{} should end in .pgm or .ppm", path.display()),
+            ))
+        }
+    };
+    std::fs::write(path, image)
+}
+
+/// The trees along the row or column through `idx`, starting at the edge in direction
+/// `dir`. Sweeping this line finds visibility from that edge, and how far each tree can
+/// see towards it.
+fn line_from_edge(dim: (usize, usize), idx: (usize, usize), dir: Dir) -> Vec<(usize, usize)> {
+    let (nrows, ncols) = dim;
+    let (ridx, cidx) = idx;
+    match dir {
+        Dir::Up => (0..nrows).map(|r| (r, cidx)).collect(),
+        Dir::Down => (0..nrows).rev().map(|r| (r, cidx)).collect(),
+        Dir::Left => (0..ncols).map(|c| (ridx, c)).collect(),
+        Dir::Right => (0..ncols).rev().map(|c| (ridx, c)).collect(),
+    }
+}
+
+/// A forest whose trees can change height. It keeps the visibility and viewing distance
+/// of every tree in each direction, so that after an edit only the row and column
+/// through the tree are swept again.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Forest {
+    heights: Array2<u8>,
+    /// Whether each tree can be seen from each edge, in the order of [`Dir::ALL`]
+    visible: [Array2<bool>; 4],
+    /// How far each tree can see in each direction, in the order of [`Dir::ALL`]
+    distances: [Array2<usize>; 4],
+    /// How many trees can be seen from any edge
+    visible_count: usize,
+    /// How many trees have each scenic score
+    score_counts: BTreeMap<usize, usize>,
+}
+
+impl Forest {
+    pub fn new(heights: Array2<u8>) -> Forest {
+        let dim = heights.dim();
+        let mut forest = Forest {
+            heights,
+            visible: Dir::ALL.map(|_| Array2::from_elem(dim, false)),
+            distances: Dir::ALL.map(|_| Array2::zeros(dim)),
+            visible_count: 0,
+            score_counts: BTreeMap::new(),
+        };
+        for ridx in 0..dim.0 {
+            forest.sweep(Dir::Left, (ridx, 0));
+            forest.sweep(Dir::Right, (ridx, 0));
+        }
+        for cidx in 0..dim.1 {
+            forest.sweep(Dir::Up, (0, cidx));
+            forest.sweep(Dir::Down, (0, cidx));
+        }
+        let trees: Vec<(usize, usize)> =
+            forest.heights.indexed_iter().map(|(idx, _)| idx).collect();
+        for idx in trees {
+            forest.count(idx, true);
+        }
+        forest
+    }
+
+    pub fn heights(&self) -> ArrayView2<'_, u8> {
+        self.heights.view()
+    }
+
+    /// How many trees can be seen from outside the forest, the same as [`part1`]
+    pub fn visible_count(&self) -> usize {
+        self.visible_count
+    }
+
+    /// The highest scenic score in the forest, the same as [`part2`]
+    pub fn best_scenic_score(&self) -> usize {
+        self.score_counts
+            .keys()
+            .next_back()
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn is_visible(&self, idx: (usize, usize)) -> bool {
+        self.visible.iter().any(|v| v[idx])
+    }
+
+    pub fn scenic_score(&self, idx: (usize, usize)) -> usize {
+        self.distances.iter().map(|d| d[idx]).product()
+    }
+
+    /// Change the height of the tree at `idx`. Only the trees in the same row or column
+    /// can change how far they see or whether they can be seen, so only they are looked
+    /// at again.
+    pub fn set_height(&mut self, idx: (usize, usize), height: u8) {
+        if self.heights[idx] == height {
+            return;
+        }
+        let (nrows, ncols) = self.heights.dim();
+        let (ridx, cidx) = idx;
+        let affected: Vec<(usize, usize)> = (0..ncols)
+            .map(|c| (ridx, c))
+            .chain((0..nrows).filter(|&r| r != ridx).map(|r| (r, cidx)))
+            .collect();
+
+        for &tree in &affected {
+            self.count(tree, false);
+        }
+        self.heights[idx] = height;
+        for dir in Dir::ALL {
+            self.sweep(dir, idx);
+        }
+        for &tree in &affected {
+            self.count(tree, true);
+        }
+    }
+
+    /// Recompute the visibility from the edge in direction `dir`, and the viewing
+    /// distance towards it, along the row or column through `idx`
+    fn sweep(&mut self, dir: Dir, idx: (usize, usize)) {
+        let d = Dir::ALL.iter().position(|&other| other == dir).unwrap();
+        let line = line_from_edge(self.heights.dim(), idx, dir);
+        for &tree in &line {
+            self.visible[d][tree] = false;
+        }
+        mark_visible(
+            self.heights.view(),
+            &mut self.visible[d],
+            line.iter().copied(),
+        );
+        let distances = &mut self.distances[d];
+        sweep_viewing_distances(self.heights.view(), line.into_iter(), |tree, distance| {
+            distances[tree] = distance
+        });
+    }
+
+    /// Add the tree at `idx` to the visible count and the scenic scores, or take it away
+    fn count(&mut self, idx: (usize, usize), add: bool) {
+        let visible = usize::from(self.is_visible(idx));
+        let score = self.scenic_score(idx);
+        if add {
+            self.visible_count += visible;
+            *self.score_counts.entry(score).or_default() += 1;
+        } else {
+            self.visible_count -= visible;
+            let count = self
+                .score_counts
+                .get_mut(&score)
+                .expect("Every tree is counted");
+            *count -= 1;
+            if *count == 0 {
+                self.score_counts.remove(&score);
+            }
+        }
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    type Input = Array2<u8>;
+    type Answer = usize;
+
+    fn parse(input_str: &str) -> Result<Self::Input, ParseError> {
+        parse(input_str)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer {
+        part1(input.view())
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer {
+        part2(input.view())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{STEPS_4, STEPS_8};
+    use ndarray::arr2;
+
+    #[test]
+    fn test_parse() {
+        let input_str = "30373
+25512
+65332
+33549
+35390";
+
+        let want = arr2(&[
+            [3, 0, 3, 7, 3],
+            [2, 5, 5, 1, 2],
+            [6, 5, 3, 3, 2],
+            [3, 3, 5, 4, 9],
+            [3, 5, 3, 9, 0],
+        ]);
+        let got = parse(input_str).unwrap();
+        assert_eq!(want, got);
+
+        let got = parse(&input_str.replace('\n', "\r\n")).unwrap();
+        assert_eq!(want, got);
+        assert_eq!((0, 0), parse("").unwrap().dim());
+        assert_eq!(0, part1(parse("").unwrap().view()));
+        assert_eq!(0, part2(parse("").unwrap().view()));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let got = parse("303\n2 5\n653");
+        let want = ParseError::new(8, " ", "a digit in column 2").at_line(2);
+        assert_eq!(Err(want), got);
+
+        let got = parse("303\r\n25\r5\r\n653").map_err(|e| (e.line, e.expected));
+        assert_eq!(Err((2, "a digit in column 3".to_string())), got);
+
+        let got = parse("303\n255\n65").map_err(|e| (e.line, e.expected));
+        let want = "a row of 3 characters, like the first, but column 3 is missing";
+        assert_eq!(Err((3, want.to_string())), got);
+
+        // Whitespace, even a lone newline, is not an empty forest
+        let got = parse("  \n").map_err(|e| (e.line, e.expected));
+        assert_eq!(Err((1, "a digit in column 1".to_string())), got);
+        assert!(parse("\n \n").is_err());
+        assert!(parse("\n").is_err());
+    }
+
+    #[test]
+    fn test_visible_from_top() {
+        let arr = arr2(&[
+            [3, 0, 3, 7, 3],
+            [2, 5, 5, 1, 2],
+            [6, 5, 3, 3, 2],
+            [3, 3, 5, 4, 9],
+            [3, 5, 3, 9, 0],
+        ]);
+        // Test an item at the top, hidden, behind an item at the same height
+        let indices = [(0, 0), (1, 3), (2, 1)];
+        let want = [true, false, false];
+
+        for (idx, w) in indices.iter().zip(want.iter()) {
+            if *w {
+                assert!(visible_from_top(arr.view(), *idx))
+            } else {
+                assert!(!visible_from_top(arr.view(), *idx))
+            }
+        }
+    }
+
+    #[test]
+    fn test_visible_from_left() {
+        let arr = arr2(&[
+            [3, 0, 3, 7, 3],
+            [2, 5, 5, 1, 2],
+            [6, 5, 3, 3, 2],
+            [3, 3, 5, 4, 9],
+            [3, 5, 3, 9, 0],
+        ]);
+        // Test an item at the left, not hidden second col, hidden, behind an item at the same height
+        let indices = [(0, 0), (1, 1), (2, 1), (3, 1)];
+        let want = [true, true, false, false];
+
+        for (idx, w) in indices.iter().zip(want.iter()) {
+            if *w {
+                assert!(visible_from_left(arr.view(), *idx))
+            } else {
+                assert!(!visible_from_left(arr.view(), *idx))
+            }
+        }
+    }
+
+    #[test]
+    fn test_visible_from_right() {
+        let arr = arr2(&[
+            [3, 0, 3, 7, 3],
+            [2, 5, 5, 1, 2],
+            [6, 5, 3, 3, 2],
+            [3, 3, 5, 4, 9],
+            [3, 5, 3, 9, 0],
+        ]);
+        // Test an item at the right, not hidden second col, hidden, hidden
+        let indices = [(0, 4), (0, 3), (1, 3), (3, 3)];
+        let want = [true, true, false, false];
+
+        for (idx, w) in indices.iter().zip(want.iter()) {
+            if *w {
+                assert!(visible_from_right(arr.view(), *idx))
+            } else {
+                assert!(!visible_from_right(arr.view(), *idx))
+            }
+        }
+    }
+
+    #[test]
+    fn test_visible_from_bottom() {
+        let arr = arr2(&[
+            [3, 0, 3, 7, 3],
+            [2, 5, 5, 1, 2],
+            [6, 5, 3, 3, 2],
+            [3, 3, 5, 4, 9],
+            [3, 5, 3, 9, 0],
+        ]);
+        // Test an item below, not hidden second bottom row, hidden, hidden
+        let indices = [(4, 1), (3, 2), (3, 3), (3, 0)];
+        let want = [true, true, false, false];
+
+        for (idx, w) in indices.iter().zip(want.iter()) {
+            if *w {
+                let got = visible_from_bottom(arr.view(), *idx);
+                assert!(got);
+            } else {
+                let got = visible_from_bottom(arr.view(), *idx);
+                assert!(!got);
+            }
+        }
+    }
+
+    #[test]
+    fn test_part1() {
+        let arr = arr2(&[
+            [3, 0, 3, 7, 3],
+            [2, 5, 5, 1, 2],
+            [6, 5, 3, 3, 2],
+            [3, 3, 5, 4, 9],
+            [3, 5, 3, 9, 0],
+        ]);
+        let want = 21;
+        let got = part1(arr.view());
+        assert_eq!(want, got);
+    }
+
+    #[test]
+    fn test_visibility_map() {
+        let arr = arr2(&[
+            [3, 0, 3, 7, 3],
+            [2, 5, 5, 1, 2],
+            [6, 5, 3, 3, 2],
+            [3, 3, 5, 4, 9],
+            [3, 5, 3, 9, 0],
+        ]);
+        let want = arr2(&[
+            [true, true, true, true, true],
+            [true, true, true, false, true],
+            [true, true, false, true, true],
+            [true, false, true, false, true],
+            [true, true, true, true, true],
+        ]);
+        assert_eq!(want, visibility_map(arr.view()));
+
+        // Check every tree of some random forests, including ones only a tree wide
+        for (seed, size) in [(1, 40), (2, 1), (3, 2)] {
+            let arr = parse(&crate::gen::generate(8, seed, size).unwrap()).unwrap();
+            let visible = visibility_map(arr.view());
+            for (idx, v) in visible.indexed_iter() {
+                assert_eq!(is_visible(arr.view(), idx), *v, "{:?}", idx);
+            }
+        }
+    }
+
+    #[test]
+    fn test_inds_above() {
+        let to_test = [0, 1, 4];
+        let want = [vec![], vec![(0, 0)], vec![(3, 0), (2, 0), (1, 0), (0, 0)]];
+        for (t, w) in to_test.iter().zip(want.iter()) {
+            let got: Vec<_> = inds_above((*t, 0)).collect();
+            assert_eq!(*w, got);
+        }
+    }
+
+    #[test]
+    fn test_inds_left() {
+        let to_test = [0, 1, 4];
+        let want = [vec![], vec![(0, 0)], vec![(0, 3), (0, 2), (0, 1), (0, 0)]];
+        for (t, w) in to_test.iter().zip(want.iter()) {
+            let got: Vec<_> = inds_left((0, *t)).collect();
+            assert_eq!(*w, got);
+        }
+    }
+
+    #[test]
+    fn test_inds_below() {
+        let arr = arr2(&[
+            [3, 0, 3, 7, 3],
+            [2, 5, 5, 1, 2],
+            [6, 5, 3, 3, 2],
+            [3, 3, 5, 4, 9],
+            [3, 5, 3, 9, 0],
+        ]);
+        let to_test = [4, 3, 1];
+        let want = [vec![], vec![(4, 0)], vec![(2, 0), (3, 0), (4, 0)]];
+        for (t, w) in to_test.iter().zip(want.iter()) {
+            let got: Vec<_> = inds_below(arr.view(), (*t, 0)).collect();
+            assert_eq!(*w, got);
+        }
+    }
+
+    #[test]
+    fn test_inds_right() {
+        let arr = arr2(&[
+            [3, 0, 3, 7, 3],
+            [2, 5, 5, 1, 2],
+            [6, 5, 3, 3, 2],
+            [3, 3, 5, 4, 9],
+            [3, 5, 3, 9, 0],
+        ]);
+        let to_test = [4, 3, 1];
+        let want = [vec![], vec![(0, 4)], vec![(0, 2), (0, 3), (0, 4)]];
+        for (t, w) in to_test.iter().zip(want.iter()) {
+            let got: Vec<_> = inds_right(arr.view(), (0, *t)).collect();
+            assert_eq!(*w, got);
+        }
+    }
+
+    #[test]
+    fn test_get_scenic_score() {
+        let arr = arr2(&[
+            [3, 0, 3, 7, 3],
+            [2, 5, 5, 1, 2],
+            [6, 5, 3, 3, 2],
+            [3, 3, 5, 4, 9],
+            [3, 5, 3, 9, 0],
+        ]);
+        let idx = (1, 2);
+        let want = 4;
+        let got = get_scenic_score(arr.view(), idx);
+        assert_eq!(want, got);
+
+        let idx = (3, 2);
+        let want = 8;
+        let got = get_scenic_score(arr.view(), idx);
+        assert_eq!(want, got);
+    }
+
+    #[test]
+    fn test_scenic_scores() {
+        let arr = arr2(&[
+            [3, 0, 3, 7, 3],
+            [2, 5, 5, 1, 2],
+            [6, 5, 3, 3, 2],
+            [3, 3, 5, 4, 9],
+            [3, 5, 3, 9, 0],
+        ]);
+        let want = arr2(&[
+            [0, 0, 0, 0, 0],
+            [0, 1, 4, 1, 0],
+            [0, 6, 1, 2, 0],
+            [0, 1, 8, 3, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        assert_eq!(want, scenic_scores(arr.view()));
+
+        for (seed, size) in [(1, 40), (2, 1), (3, 2), (4, 3)] {
+            let arr = parse(&crate::gen::generate(8, seed, size).unwrap()).unwrap();
+            let scores = scenic_scores(arr.view());
+            for (idx, score) in scores.indexed_iter() {
+                assert_eq!(get_scenic_score(arr.view(), idx), *score, "{:?}", idx);
+            }
+        }
+    }
+
+    #[test]
+    fn test_viewing_distances() {
+        let arr = arr2(&[
+            [3, 0, 3, 7, 3],
+            [2, 5, 5, 1, 2],
+            [6, 5, 3, 3, 2],
+            [3, 3, 5, 4, 9],
+            [3, 5, 3, 9, 0],
+        ]);
+        assert_eq!([1, 2, 2, 1], viewing_distances(arr.view(), (1, 2)));
+        assert_eq!([2, 2, 1, 2], viewing_distances(arr.view(), (3, 2)));
+        assert_eq!(0, viewing_distance(arr.view(), (0, 3), Dir::Up));
+        assert_eq!(4, viewing_distance(arr.view(), (3, 4), Dir::Left));
+
+        let arr = parse(&crate::gen::generate(8, 5, 20).unwrap()).unwrap();
+        for (idx, &score) in scenic_scores(arr.view()).indexed_iter() {
+            let got: usize = viewing_distances(arr.view(), idx).iter().product();
+            assert_eq!(score, got);
+        }
+    }
+
+    #[test]
+    fn test_top_scenic_trees() {
+        let arr = arr2(&[
+            [3, 0, 3, 7, 3],
+            [2, 5, 5, 1, 2],
+            [6, 5, 3, 3, 2],
+            [3, 3, 5, 4, 9],
+            [3, 5, 3, 9, 0],
+        ]);
+        let want = vec![((3, 2), 8), ((2, 1), 6), ((1, 2), 4)];
+        assert_eq!(want, top_scenic_trees(arr.view(), 3));
+        assert_eq!(want[..1], top_scenic_trees(arr.view(), 1));
+        assert!(top_scenic_trees(arr.view(), 0).is_empty());
+
+        // Ties are in reading order, and there are only 25 trees to give
+        let all = top_scenic_trees(arr.view(), 100);
+        assert_eq!(25, all.len());
+        assert_eq!([((2, 3), 2), ((1, 1), 1), ((1, 3), 1)], all[4..7]);
+    }
+
+    #[test]
+    fn test_visible_from_edge() {
+        let arr = arr2(&[
+            [3, 0, 3, 7, 3],
+            [2, 5, 5, 1, 2],
+            [6, 5, 3, 3, 2],
+            [3, 3, 5, 4, 9],
+            [3, 5, 3, 9, 0],
+        ]);
+        let got = visible_from_edge(arr.view(), Dir::Left);
+        assert_eq!([true, true, false, false, false], got.row(1).to_vec()[..]);
+
+        let reference = [
+            (
+                Dir::Up,
+                visible_from_top as fn(ArrayView2<u8>, (usize, usize)) -> bool,
+            ),
+            (Dir::Right, visible_from_right),
+            (Dir::Down, visible_from_bottom),
+            (Dir::Left, visible_from_left),
+        ];
+        let forest = parse(&crate::gen::generate(8, 6, 20).unwrap()).unwrap();
+        for arr in [arr.view(), forest.view()] {
+            for (edge, visible_from) in reference {
+                for (idx, &v) in visible_from_edge(arr, edge).indexed_iter() {
+                    assert_eq!(visible_from(arr, idx), v, "{:?} {:?}", edge, idx);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_forest() {
+        let arr = arr2(&[
+            [3, 0, 3, 7, 3],
+            [2, 5, 5, 1, 2],
+            [6, 5, 3, 3, 2],
+            [3, 3, 5, 4, 9],
+            [3, 5, 3, 9, 0],
+        ]);
+        let mut forest = Forest::new(arr.clone());
+        assert_eq!(21, forest.visible_count());
+        assert_eq!(8, forest.best_scenic_score());
+
+        // Raising the best tree lets it see all the way up
+        forest.set_height((3, 2), 9);
+        assert_eq!(part1(forest.heights()), forest.visible_count());
+        assert_eq!(part2(forest.heights()), forest.best_scenic_score());
+        assert_eq!(12, forest.scenic_score((3, 2)));
+        assert!(forest.is_visible((3, 2)));
+
+        forest.set_height((3, 2), 5);
+        assert_eq!(Forest::new(arr), forest);
+
+        let mut rng = crate::gen::Rng::new(7);
+        let mut forest = Forest::new(parse(&crate::gen::generate(8, 7, 15).unwrap()).unwrap());
+        for _ in 0..200 {
+            let idx = (rng.below(15), rng.below(15));
+            forest.set_height(idx, rng.range(0, 9) as u8);
+            assert_eq!(part1(forest.heights()), forest.visible_count());
+            assert_eq!(part2(forest.heights()), forest.best_scenic_score());
+        }
+        assert_eq!(Forest::new(forest.heights().to_owned()), forest);
+        assert_eq!(0, Forest::new(Array2::zeros((0, 0))).best_scenic_score());
+    }
+
+    #[test]
+    fn test_sightlines() {
+        let arr = arr2(&[
+            [3, 0, 3, 7, 3],
+            [2, 5, 5, 1, 2],
+            [6, 5, 3, 3, 2],
+            [3, 3, 5, 4, 9],
+            [3, 5, 3, 9, 0],
+        ]);
+        // The middle tree is hidden in all eight directions, but a knight's move
+        // away is a lower tree at the edge
+        assert!(!is_visible_along(arr.view(), (2, 2), &STEPS_8));
+        assert_eq!(2, scenic_score_along(arr.view(), (2, 2), &STEPS_8));
+        assert!(is_visible_along(arr.view(), (2, 2), KNIGHT));
+        assert_eq!(1, scenic_score_along(arr.view(), (2, 2), KNIGHT));
+        assert_eq!(0, scenic_score_along(arr.view(), (1, 1), KNIGHT));
+
+        // Looking along the rows and columns is the puzzle
+        let forest = parse(&crate::gen::generate(8, 8, 20).unwrap()).unwrap();
+        for arr in [arr.view(), forest.view()] {
+            assert_eq!(part1(arr), count_visible_along(arr, &STEPS_4));
+            assert_eq!(part2(arr), best_scenic_score_along(arr, &STEPS_4));
+            assert!(count_visible_along(arr, &STEPS_8) >= part1(arr));
+        }
+    }
+
+    #[test]
+    fn test_heatmap() {
+        let arr = arr2(&[
+            [3, 0, 3, 7, 3],
+            [2, 5, 5, 1, 2],
+            [6, 5, 3, 3, 2],
+            [3, 3, 5, 4, 9],
+            [3, 5, 3, 9, 0],
+        ]);
+        assert_eq!(Some((3, 2)), best_tree(arr.view()));
+        assert_eq!(None, best_tree(Array2::zeros((0, 0)).view()));
+
+        let heights = heatmap(arr.view(), Layer::Heights);
+        assert_eq!([85, 0, 85, 198, 85], heights.row(0).to_vec()[..]);
+        assert_eq!(255, heights[(3, 4)]);
+        let visibility = heatmap(arr.view(), Layer::Visibility);
+        assert_eq!([255, 255, 0, 255, 255], visibility.row(2).to_vec()[..]);
+        let scores = heatmap(arr.view(), Layer::ScenicScores);
+        assert_eq!([0, 31, 255, 95, 0], scores.row(3).to_vec()[..]);
+
+        assert_eq!([68, 1, 84], colour(0));
+        assert_eq!([253, 231, 37], colour(255));
+    }
+
+    #[test]
+    fn test_image_formats() {
+        let levels = arr2(&[[0, 255, 128], [10, 20, 30]]);
+
+        let pgm = to_pgm(levels.view());
+        let header = b"P5\n3 2\n255\n";
+        assert_eq!(header, &pgm[..header.len()]);
+        assert_eq!([0, 255, 128, 10, 20, 30], pgm[header.len()..]);
+
+        let ppm = to_ppm(levels.view(), Some((1, 0)));
+        let header = b"P6\n3 2\n255\n";
+        assert_eq!(header, &ppm[..header.len()]);
+        let pixels = &ppm[header.len()..];
+        assert_eq!(18, pixels.len());
+        assert_eq!(colour(255), pixels[3..6]);
+        assert_eq!(HIGHLIGHT, pixels[9..12]);
+
+        let ansi = to_ansi(levels.view(), Some((0, 2)));
+        let lines: Vec<&str> = ansi.lines().collect();
+        assert_eq!(2, lines.len());
+        assert!(lines[0].starts_with("\x1b[48;2;68;1;84m  "));
+        assert!(lines[0].ends_with("\x1b[48;2;255;0;0m**\x1b[0m"));
+        assert_eq!(3, lines[1].matches("  ").count());
+    }
+
+    #[test]
+    fn test_save_heatmap() {
+        let arr = parse("30373\n25512\n65332\n33549\n35390").unwrap();
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("aoc_2022_day08_{}.ppm", std::process::id()));
+        save_heatmap(arr.view(), Layer::ScenicScores, &path).unwrap();
+        let image = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(
+            to_ppm(
+                heatmap(arr.view(), Layer::ScenicScores).view(),
+                Some((3, 2))
+            ),
+            image
+        );
+
+        let path = dir.join("forest.png");
+        assert!(save_heatmap(arr.view(), Layer::Heights, &path).is_err());
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_part2() {
+        let arr = arr2(&[
+            [3, 0, 3, 7, 3],
+            [2, 5, 5, 1, 2],
+            [6, 5, 3, 3, 2],
+            [3, 3, 5, 4, 9],
+            [3, 5, 3, 9, 0],
+        ]);
+        let want = 8;
+        let got = part2(arr.view());
+        assert_eq!(want, got);
+    }
+}
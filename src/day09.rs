@@ -0,0 +1,294 @@
+use std::collections::HashSet;
+
+use crate::error::{parse_lines, ParseError};
+use crate::geom::{self, Coord, Dir};
+use crate::Solution;
+
+pub fn parse(input_str: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse_lines(input_str, Instruction::parse)
+}
+
+fn parse_dir(input_str: &str) -> Result<Dir, ParseError> {
+    match input_str {
+        "U" => Ok(Dir::Up),
+        "D" => Ok(Dir::Down),
+        "L" => Ok(Dir::Left),
+        "R" => Ok(Dir::Right),
+        _ => Err(ParseError::new(Day09::DAY, input_str, "U, D, L, or R")),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    dir: Dir,
+    count: u8,
+}
+
+impl Instruction {
+    fn parse(input_str: &str) -> Result<Instruction, ParseError> {
+        let (dir_s, n_s) = input_str.split_once(' ').ok_or_else(|| {
+            ParseError::new(Day09::DAY, input_str, "a direction and a count like 'R 4'")
+        })?;
+        let dir = parse_dir(dir_s)?;
+        let count: u8 = n_s
+            .parse()
+            .map_err(|_| ParseError::new(Day09::DAY, n_s, "a count from 0 to 255"))?;
+        Ok(Instruction { dir, count })
+    }
+}
+
+/// Knots can end up either side of the start, so they need signed coordinates. A count
+/// can be up to 255, so an i32 runs out after about 8.4 million lines, but an i64 would
+/// need far more lines than fit in memory.
+pub type Point = geom::Point<i64>;
+
+/// A rope of knots, each of which follows the knot in front of it. The first knot is
+/// the head, and the last is the tail.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rope {
+    knots: Vec<Point>,
+}
+
+impl Rope {
+    /// A rope of `n_knots` knots, all at the start
+    pub fn new(n_knots: usize) -> Rope {
+        assert!(n_knots > 0, "A rope needs at least one knot");
+        Rope {
+            knots: vec![Point::new(0, 0); n_knots],
+        }
+    }
+
+    pub fn knots(&self) -> &[Point] {
+        &self.knots
+    }
+
+    pub fn head(&self) -> Point {
+        self.knots[0]
+    }
+
+    pub fn tail(&self) -> Point {
+        self.knots[self.knots.len() - 1]
+    }
+
+    /// Move the head one step in direction `d`, and have the rest of the rope follow
+    pub fn step(&mut self, d: Dir) {
+        self.knots[0] = self.knots[0]
+            .step(d)
+            .expect("An i64 has room for more steps than any input has");
+        for idx in 1..self.knots.len() {
+            let leader = self.knots[idx - 1];
+            let knot = self.knots[idx];
+            // A knot that is still touching stays put, and so does everything behind it
+            if leader.chebyshev(knot) <= 1 {
+                break;
+            }
+            self.knots[idx] = follow(knot, leader);
+        }
+    }
+}
+
+/// Where `knot` moves to when `leader` has pulled away from it. It takes one step
+/// towards the leader along each axis they differ on, so it moves diagonally unless
+/// they share a row or column.
+fn follow(knot: Point, leader: Point) -> Point {
+    // A step towards the leader never goes past it, so it is always in range
+    let towards = |from: i64, to: i64| {
+        from.step((to - from).signum() as i8)
+            .expect("A step towards the leader stays in range")
+    };
+    Point::new(towards(knot.x, leader.x), towards(knot.y, leader.y))
+}
+
+/// The number of places the tail of a rope of `n_knots` knots visits, including the
+/// start
+pub fn tail_visits(instructions: &[Instruction], n_knots: usize) -> usize {
+    let mut rope = Rope::new(n_knots);
+    let mut visited: HashSet<Point> = HashSet::from([rope.tail()]);
+
+    for inst in instructions {
+        for _ in 0..inst.count {
+            rope.step(inst.dir);
+            visited.insert(rope.tail());
+        }
+    }
+    visited.len()
+}
+
+/// A rope with just a head and a tail
+pub fn part1(instructions: &[Instruction]) -> usize {
+    tail_visits(instructions, 2)
+}
+
+/// A rope of ten knots
+pub fn part2(instructions: &[Instruction]) -> usize {
+    tail_visits(instructions, 10)
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    type Input = Vec<Instruction>;
+    type Answer = usize;
+
+    fn parse(input_str: &str) -> Result<Self::Input, ParseError> {
+        parse(input_str)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let input_str = "R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2";
+        let want = vec![
+            Instruction {
+                dir: Dir::Right,
+                count: 4,
+            },
+            Instruction {
+                dir: Dir::Up,
+                count: 4,
+            },
+            Instruction {
+                dir: Dir::Left,
+                count: 3,
+            },
+            Instruction {
+                dir: Dir::Down,
+                count: 1,
+            },
+            Instruction {
+                dir: Dir::Right,
+                count: 4,
+            },
+            Instruction {
+                dir: Dir::Down,
+                count: 1,
+            },
+            Instruction {
+                dir: Dir::Left,
+                count: 5,
+            },
+            Instruction {
+                dir: Dir::Right,
+                count: 2,
+            },
+        ];
+        let got = parse(input_str).unwrap();
+        assert_eq!(want, got);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input_str = "R 4
+U 4
+X 3";
+        let want = ParseError {
+            day: 9,
+            line: 3,
+            text: "X".to_string(),
+            expected: "U, D, L, or R".to_string(),
+        };
+        assert_eq!(Err(want), parse(input_str));
+
+        let got = parse("R 4\nU -4").map_err(|e| (e.line, e.text));
+        assert_eq!(Err((2, "-4".to_string())), got);
+    }
+
+    #[test]
+    fn test_follow() {
+        let knot = Point::new(0, 0);
+        assert_eq!(Point::new(1, 0), follow(knot, Point::new(2, 0)));
+        assert_eq!(Point::new(0, -1), follow(knot, Point::new(0, -2)));
+        // Off in both directions means a diagonal step
+        assert_eq!(Point::new(1, -1), follow(knot, Point::new(2, -1)));
+        assert_eq!(Point::new(-1, 1), follow(knot, Point::new(-2, 2)));
+    }
+
+    #[test]
+    fn test_rope() {
+        // The state after "R 4" then "U 4" in the larger example
+        let mut rope = Rope::new(10);
+        for d in [Dir::Right; 4].into_iter().chain([Dir::Up; 4]) {
+            rope.step(d);
+        }
+        let want =
+            [(4, -4), (4, -3), (4, -2), (3, -2), (2, -2), (1, -1)].map(|(x, y)| Point::new(x, y));
+        assert_eq!(want, rope.knots()[..6]);
+        assert_eq!(Point::new(0, 0), rope.tail());
+
+        let instructions = parse("R 4\nU 4\nL 3\nD 1").unwrap();
+        // A rope of one knot is all head, and visits every place it steps on
+        assert_eq!(13, tail_visits(&instructions, 1));
+        assert_eq!(part1(&instructions), tail_visits(&instructions, 2));
+
+        // Further from the start than an i16 can count
+        let input_str = vec!["R 255"; 130].join("\n");
+        let instructions = parse(&input_str).unwrap();
+        assert_eq!(33150, part1(&instructions));
+        assert_eq!(33142, part2(&instructions));
+    }
+
+    #[test]
+    fn test_part1() {
+        let input_str = "R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2";
+        let instructions = parse(input_str).unwrap();
+        let want = 13;
+        let got = part1(&instructions);
+        assert_eq!(want, got);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input_str = "R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2";
+        let instructions = parse(input_str).unwrap();
+        let want = 1;
+        let got = part2(&instructions);
+        assert_eq!(want, got);
+
+        let input_str = "R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20";
+        let instructions = parse(input_str).unwrap();
+        let want = 36;
+        let got = part2(&instructions);
+        assert_eq!(want, got);
+    }
+}
@@ -1,34 +1,47 @@
+use crate::error::{parse_lines, ParseError};
+use crate::Solution;
+
 #[derive(PartialEq, Debug, Clone, Copy)]
-enum Instruction {
+pub enum Instruction {
     Addx(i64),
     Noop,
 }
 
-impl From<&str> for Instruction {
+impl TryFrom<&str> for Instruction {
+    type Error = ParseError;
+
     /// parses `addx V` or `noop` into an Instruction
-    fn from(value: &str) -> Self {
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let bad_instruction = || ParseError::new(Day10::DAY, value, "'addx V' or 'noop'");
         let mut parts = value.split_whitespace();
         let instruction = parts.next();
-        if instruction == Some("noop") {
-            return Instruction::Noop;
+        let result = if instruction == Some("noop") {
+            Instruction::Noop
         } else if instruction == Some("addx") {
             let number: i64 = parts
                 .next()
-                .expect("No number to parse after addx")
-                .parse()
-                .expect("Could not parse number after addx");
-            return Instruction::Addx(number);
+                .and_then(|n| n.parse().ok())
+                .ok_or_else(bad_instruction)?;
+            Instruction::Addx(number)
+        } else {
+            return Err(bad_instruction());
+        };
+
+        // Nothing is allowed after the instruction
+        match parts.next() {
+            Some(_) => Err(bad_instruction()),
+            None => Ok(result),
         }
-        panic!("Could not parse line of input")
     }
 }
 
-fn parse(s: &str) -> Vec<Instruction> {
-    s.lines().map(Instruction::from).collect()
+pub fn parse(s: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse_lines(s, |line| Instruction::try_from(line))
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
-struct CPU {
+#[allow(clippy::upper_case_acronyms)]
+pub struct CPU {
     cycle: i64,
     register_during: i64,
     register_after: i64,
@@ -63,7 +76,7 @@ impl CPU {
 
 /// Sum the signal strength at cycles 20, 60, 100, 140, 180, 220.
 /// Signal strength is the cycle number multiplied by the value of the register
-fn part1(instructions: &[Instruction]) -> i64 {
+pub fn part1(instructions: &[Instruction]) -> i64 {
     let mut desired_cycles = vec![20, 60, 100, 140, 180, 220];
     desired_cycles.reverse();
     let mut next_goal_cycle = desired_cycles.pop().expect("Nothing in desired_cycles");
@@ -111,22 +124,31 @@ fn part1(instructions: &[Instruction]) -> i64 {
     total_signal_strength
 }
 
-fn part2(instructions: &[Instruction]) -> Vec<String> {
+pub fn part2(instructions: &[Instruction]) -> Vec<String> {
     // The raw pixels. '.' by default
     let mut pixels = [['.'; 40]; 6];
 
     // Create the CPU
     let mut cpu = CPU::new();
-    let mut instr_idx = 0;
 
-    // Iterate over each row
-    for (row_idx, row) in pixels.iter().enumerate() {
-        // And each column
-        for (col_idx, pixel) in row.iter().enumerate() {
-            // If the sprite overlaps with (row_idx, col_idx), we set pixel to '#'
+    for inst in instructions {
+        let next_cpu = cpu.step(*inst);
+
+        // Every cycle this instruction takes sees the register value from before it
+        for cycle in cpu.cycle..next_cpu.cycle {
+            let row_idx = (cycle / 40) as usize;
+            let col_idx = cycle % 40;
+            if row_idx >= pixels.len() {
+                break;
+            }
 
-            // Check if we need to go to the next instruction
+            // If the sprite overlaps with (row_idx, col_idx), we set pixel to '#'
+            if (next_cpu.register_during - col_idx).abs() <= 1 {
+                pixels[row_idx][col_idx as usize] = '#';
+            }
         }
+
+        cpu = next_cpu;
     }
 
     // Convert to Strings and return
@@ -136,31 +158,26 @@ fn part2(instructions: &[Instruction]) -> Vec<String> {
         .collect()
 }
 
-fn main() {
-    let setup_time = std::time::Instant::now();
-
-    // Read the input file for day 1
-    let input_str =
-        std::fs::read_to_string("input/day10.txt").expect("Failed to read day 10 input file");
+pub struct Day10;
 
-    // Parse the input into a vector of numbers
-    let input = parse(&input_str);
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = Vec<Instruction>;
+    /// Part 2 draws letters on the CRT, so both answers are text
+    type Answer = String;
 
-    println!("Setup took {:.6} µs", setup_time.elapsed().as_micros());
-
-    // Part 1
-    let part1_time = std::time::Instant::now();
-    let part1_result = part1(&input);
-    println!("Part 1 took {:.6} ns", part1_time.elapsed().as_nanos());
+    fn parse(input_str: &str) -> Result<Self::Input, ParseError> {
+        parse(input_str)
+    }
 
-    // Part 2
-    // let part2_time = std::time::Instant::now();
-    // let part2_result = part2(input);
-    // println!("Part 2 took {:.6} µs", part2_time.elapsed().as_micros());
+    fn part1(input: &Self::Input) -> Self::Answer {
+        part1(input).to_string()
+    }
 
-    println!();
-    println!("Part 1 result: {}", part1_result);
-    // println!("Part 2 result: {}", part2_result);
+    /// The rows of the CRT, one per line
+    fn part2(input: &Self::Input) -> Self::Answer {
+        part2(input).join("\n")
+    }
 }
 
 #[cfg(test)]
@@ -171,7 +188,7 @@ mod tests {
     fn test_parse_instruction1() {
         let s = "addx 3";
         let want = Instruction::Addx(3);
-        let got = Instruction::from(s);
+        let got = Instruction::try_from(s).unwrap();
         assert_eq!(want, got);
     }
 
@@ -179,7 +196,7 @@ mod tests {
     fn test_parse_instruction2() {
         let s = "addx -5";
         let want = Instruction::Addx(-5);
-        let got = Instruction::from(s);
+        let got = Instruction::try_from(s).unwrap();
         assert_eq!(want, got);
     }
 
@@ -187,10 +204,25 @@ mod tests {
     fn test_parse_instruction3() {
         let s = "noop";
         let want = Instruction::Noop;
-        let got = Instruction::from(s);
+        let got = Instruction::try_from(s).unwrap();
         assert_eq!(want, got);
     }
 
+    #[test]
+    fn test_parse_error() {
+        let want = ParseError {
+            day: 10,
+            line: 3,
+            text: "addx five".to_string(),
+            expected: "'addx V' or 'noop'".to_string(),
+        };
+        assert_eq!(Err(want), parse("noop\naddx 3\naddx five"));
+
+        for bad in ["", "add 3", "addx", "noop 3"] {
+            assert!(Instruction::try_from(bad).is_err(), "{:?} should fail", bad);
+        }
+    }
+
     #[test]
     fn test_step_basic() {
         let start = CPU::new();
@@ -371,7 +403,7 @@ noop";
 
     #[test]
     fn test_part1() {
-        let input = parse(INPUT_STR);
+        let input = parse(INPUT_STR).unwrap();
         let want = 13140;
         let got = part1(&input);
         assert_eq!(want, got);
@@ -379,7 +411,7 @@ noop";
 
     #[test]
     fn test_part2() {
-        let input = parse(INPUT_STR);
+        let input = parse(INPUT_STR).unwrap();
         let want: Vec<String> = vec![
             "##..##..##..##..##..##..##..##..##..##..".to_string(),
             "###...###...###...###...###...###...###.".to_string(),
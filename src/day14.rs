@@ -0,0 +1,306 @@
+use std::collections::HashSet;
+
+use itertools::Itertools;
+
+use crate::error::{parse_lines, ParseError};
+use crate::geom::{self, Dir};
+use crate::Solution;
+
+/// The deepest rock can go. Sand spreads at most one step sideways for every step down
+/// from 500,0, so with the floor of part 2 two below this, sand never needs an `x`
+/// below 0.
+pub const MAX_DEPTH: u16 = 498;
+
+/// Every point of rock in the scan
+pub fn parse(input_str: &str) -> Result<HashSet<Point>, ParseError> {
+    let lines = parse_lines(input_str, parse_line)?;
+    let too_deep = lines
+        .iter()
+        .position(|points| points.iter().any(|p| p.y > MAX_DEPTH));
+    if let Some(idx) = too_deep {
+        let text = input_str.lines().nth(idx).unwrap_or_default();
+        let expected = format!("rock no deeper than y={}", MAX_DEPTH);
+        return Err(ParseError::new(Day14::DAY, text, expected).at_line(idx + 1));
+    }
+    let walls: HashSet<Point> = lines.into_iter().flatten().collect();
+    // Without any rock the sand has nothing to land on, and nowhere to fall past
+    if walls.is_empty() {
+        return Err(ParseError::new(Day14::DAY, "", "at least one path of rock")
+            .at_line(input_str.lines().count() + 1));
+    }
+    Ok(walls)
+}
+
+/// Every point on one path of rock, like `498,4 -> 498,6 -> 496,6`
+pub fn parse_line(line: &str) -> Result<Vec<Point>, ParseError> {
+    let corners: Vec<Point> = line.split(" -> ").map(parse_point).try_collect()?;
+    let mut points = Vec::new();
+    for (p1, p2) in corners.iter().tuple_windows() {
+        let between = gen_points_between(*p1, *p2).ok_or_else(|| {
+            ParseError::new(
+                Day14::DAY,
+                &format!("{},{} -> {},{}", p1.x, p1.y, p2.x, p2.y),
+                "a horizontal or vertical line",
+            )
+        })?;
+        points.extend(between);
+    }
+    Ok(points)
+}
+
+/// The scan only covers points to the right of and below the origin
+pub type Point = geom::Point<u16>;
+
+/// Assumes `s_pt` looks like "504,23"
+fn parse_point(s_pt: &str) -> Result<Point, ParseError> {
+    let bad_point = || ParseError::new(Day14::DAY, s_pt, "a point like '504,23'");
+    let (x, y) = s_pt.split_once(',').ok_or_else(bad_point)?;
+    Ok(Point {
+        x: x.parse().map_err(|_| bad_point())?,
+        y: y.parse().map_err(|_| bad_point())?,
+    })
+}
+
+/// Take two points, and draw a straight line between them. Assumes points will
+/// always either be above each eachother (vertical line) or side to side
+/// (horizontal line), and returns None if they are not
+fn gen_points_between(p: Point, o: Point) -> Option<Vec<Point>> {
+    // If xs are the same, then vertical
+    if p.x == o.x {
+        let (ysmall, ybig) = if p.y < o.y { (p.y, o.y) } else { (o.y, p.y) };
+        return Some((ysmall..=ybig).map(|y| Point { x: p.x, y }).collect());
+    } else if p.y == o.y {
+        // the ys are the same. Move horizontally
+        let (xsmall, xbig) = if p.x < o.x { (p.x, o.x) } else { (o.x, p.x) };
+        return Some((xsmall..=xbig).map(|x| Point { x, y: p.y }).collect());
+    }
+    // Points are not vertical or horizontal
+    None
+}
+
+/// Why sand never steps off the points a u16 can hold
+const IN_RANGE: &str = "parse keeps the rock shallow enough for sand to stay at x >= 0";
+
+/// Find the next place a unit of sand will fall to.
+/// First attempts to go directly down, then down and left, then down and right
+/// If those three spots exist in `walls`, then return None
+pub fn next_point(walls: &HashSet<Point>, p: Point) -> Option<Point> {
+    // Check down
+    let d = p.step(Dir::Down).expect(IN_RANGE);
+    if !walls.contains(&d) {
+        return Some(d);
+    }
+
+    let l = d.step(Dir::Left).expect(IN_RANGE);
+    if !walls.contains(&l) {
+        return Some(l);
+    }
+
+    let r = d.step(Dir::Right).expect(IN_RANGE);
+    if !walls.contains(&r) {
+        return Some(r);
+    }
+
+    None
+}
+
+pub enum SandState {
+    AtRest,
+    Abyss,
+}
+
+/// Call new_point continuously until can't go any further, or fall below bottom
+pub fn fall_forever(walls: &HashSet<Point>, point: Point, bottom: u16) -> (SandState, Point) {
+    let mut pt = point;
+
+    // Propagate the point. If it is None, then return AtRest and current point
+    // If it is a point, check if it is below the bottom
+    // If it is below the bottom, return Abyss, and the current state
+    // Else keep propagating
+    loop {
+        match next_point(walls, pt) {
+            None => {
+                return (SandState::AtRest, pt);
+            }
+            Some(p) => {
+                if p.y >= bottom {
+                    return (SandState::Abyss, p);
+                } else {
+                    pt = p;
+                }
+            }
+        }
+    }
+}
+
+/// The source of sand is (500, 0). Count how many units of sand fall before all further
+/// sand falls into the abyss
+pub fn part1(walls: &HashSet<Point>) -> usize {
+    let source = Point { x: 500, y: 0 };
+    let lowest_wall = walls
+        .iter()
+        .map(|p| p.y)
+        .max()
+        .expect("parse checks there is some rock");
+    let mut blockers = walls.clone();
+
+    let mut ctr = 0;
+    loop {
+        // Keep dropping sand until we get an abyss
+        // When we receive an AtRest, add that point to the blockers
+        match fall_forever(&blockers, source, lowest_wall) {
+            (SandState::Abyss, _) => {
+                return ctr;
+            }
+            (SandState::AtRest, pt) => {
+                blockers.insert(pt);
+            }
+        }
+        ctr += 1;
+    }
+}
+
+/// Find the next place a unit of sand will fall to.
+/// First attempts to go directly down, then down and left, then down and right
+/// If those three spots exist in `walls`, then return None
+pub fn next_point_w_floor(walls: &HashSet<Point>, p: Point, floor_height: u16) -> Option<Point> {
+    // Check down
+    let d = p.step(Dir::Down).expect(IN_RANGE);
+    if !walls.contains(&d) && (d.y < floor_height) {
+        return Some(d);
+    }
+
+    // Check left
+    let l = d.step(Dir::Left).expect(IN_RANGE);
+    if !walls.contains(&l) && (l.y < floor_height) {
+        return Some(l);
+    }
+
+    // Check right
+    let r = d.step(Dir::Right).expect(IN_RANGE);
+    if !walls.contains(&r) && (r.y < floor_height) {
+        return Some(r);
+    }
+
+    // No valid point, at rest
+    None
+}
+
+pub fn fall_to_floor(walls: &HashSet<Point>, point: Point, floor_height: u16) -> Point {
+    let mut pt = point;
+
+    // Propagate the point. If it is None, return the point
+    loop {
+        match next_point_w_floor(walls, pt, floor_height) {
+            Some(p) => {
+                pt = p;
+            }
+            None => {
+                return pt;
+            }
+        }
+    }
+}
+
+/// There is a floor 2 units below (at higher y index) the lowest wall that extends
+/// infinitely in either direction
+pub fn part2(walls: &HashSet<Point>) -> usize {
+    let source = Point { x: 500, y: 0 };
+    let lowest_wall = walls
+        .iter()
+        .map(|p| p.y)
+        .max()
+        .expect("parse checks there is some rock");
+    let mut blockers = walls.clone();
+    let floor_height = lowest_wall + 2;
+
+    let mut ctr = 0;
+    loop {
+        ctr += 1;
+        // Fall until the returned point is the source
+        let new_pt = fall_to_floor(&blockers, source, floor_height);
+        blockers.insert(new_pt);
+        if new_pt == source {
+            return ctr;
+        }
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Input = HashSet<Point>;
+    type Answer = usize;
+
+    fn parse(input_str: &str) -> Result<Self::Input, ParseError> {
+        parse(input_str)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let input_str = "498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502;9 -> 494,9";
+        let want = ParseError {
+            day: 14,
+            line: 2,
+            text: "502;9".to_string(),
+            expected: "a point like '504,23'".to_string(),
+        };
+        assert_eq!(Err(want), parse(input_str));
+
+        assert_eq!(Err(1), parse("").map_err(|e| e.line));
+        assert_eq!(Err(2), parse("498,4").map_err(|e| e.line));
+
+        // Sand could spread out past x = 0 above a floor any deeper
+        assert!(parse("0,498 -> 1000,498").is_ok());
+        let got = parse("498,4 -> 498,6\n2,499 -> 4,499").map_err(|e| (e.line, e.expected));
+        assert_eq!(Err((2, "rock no deeper than y=498".to_string())), got);
+
+        let input_str = "498,4 -> 498,6 -> 496,8";
+        let want = ParseError {
+            day: 14,
+            line: 1,
+            text: "498,6 -> 496,8".to_string(),
+            expected: "a horizontal or vertical line".to_string(),
+        };
+        assert_eq!(Err(want), parse(input_str));
+    }
+
+    #[test]
+    fn test_part1() {
+        let input_str = "498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9";
+
+        let walls = parse(input_str).unwrap();
+
+        let want = 24;
+        let got = part1(&walls);
+        assert_eq!(want, got);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input_str = "498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9";
+
+        let walls = parse(input_str).unwrap();
+
+        let want = 93;
+        let got = part2(&walls);
+        assert_eq!(want, got);
+    }
+}
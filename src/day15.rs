@@ -0,0 +1,353 @@
+use std::collections::HashSet;
+
+use itertools::Itertools;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::error::{parse_lines, ParseError};
+use crate::geom::Point;
+use crate::Solution;
+
+pub fn parse_line(line: &str) -> Result<(Point, Point), ParseError> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"x=(-?\d+), y=(-?\d+)").unwrap();
+    }
+
+    let bad_line = || {
+        ParseError::new(
+            Day15::DAY,
+            line,
+            "a sensor and beacon like 'Sensor at x=2, y=18: closest beacon is at x=-2, y=15'",
+        )
+    };
+
+    let mut result: [Point; 2] = [Point::default(); 2];
+    let mut n_points = 0;
+    for (idx, cap) in RE.captures_iter(line).enumerate() {
+        if idx >= result.len() {
+            return Err(bad_line());
+        }
+        let x: i64 = cap[1].parse().map_err(|_| bad_line())?;
+        let y: i64 = cap[2].parse().map_err(|_| bad_line())?;
+        result[idx] = Point { x, y };
+        n_points += 1;
+    }
+    if n_points != result.len() {
+        return Err(bad_line());
+    }
+    Ok((result[0], result[1]))
+}
+
+/// Split the input into the sensors, and the beacon closest to each one
+pub fn parse(input_str: &str) -> Result<(Vec<Point>, Vec<Point>), ParseError> {
+    let pairs = parse_lines(input_str, parse_line)?;
+    if pairs.is_empty() {
+        return Err(ParseError::new(Day15::DAY, "", "at least one sensor").at_line(1));
+    }
+    Ok(pairs.into_iter().unzip())
+}
+
+/// How many spots in `row` cannot have a beacon in them?
+pub fn part1(sensors: &[Point], beacons: &[Point], row: i64) -> usize {
+    // Find any beacons in the row in question
+    let beacons_in_row: HashSet<Point> = beacons.iter().filter(|&p| p.y == row).copied().collect();
+
+    let mut empty_spots: HashSet<Point> = HashSet::new();
+
+    // For each sensor, get its empty range, the range in which there is only one sensor,
+    // right at the edge.
+    let ranges = sensors
+        .iter()
+        .zip(beacons.iter())
+        .map(|(sen, beac)| sen.manhattan(*beac));
+
+    // For each sensor and distance, check if `row` is in range.
+    // If it is in range, add points to either side until they aren't in range.
+    sensors
+        .iter()
+        .zip(ranges)
+        .filter(|(sen, dist)| {
+            // Get the point on the line
+            let pt_on_line = Point { x: sen.x, y: row };
+            sen.manhattan(pt_on_line) <= *dist
+        })
+        .for_each(|(sen, dist)| {
+            // Add the point directly above or below
+            empty_spots.insert(Point { x: sen.x, y: row });
+
+            // Add points with greater x value until no longer in range
+            (sen.x..)
+                // Create the point
+                .map(|x| Point { x, y: row })
+                // Take while the points are in range
+                .take_while(|pt| sen.manhattan(*pt) <= dist)
+                // Add them to the empty spots
+                .for_each(|pt| {
+                    empty_spots.insert(pt);
+                });
+
+            // Add points with smaller x value until no longer in range
+            (i64::MIN..sen.x)
+                .rev()
+                // Create the point
+                .map(|x| Point { x, y: row })
+                // Take while the points are in range
+                .take_while(|pt| sen.manhattan(*pt) <= dist)
+                // Add them to the empty spots
+                .for_each(|pt| {
+                    empty_spots.insert(pt);
+                });
+        });
+
+    // At the end, remove the `beacons_in_row` from the `empty_spots`
+    empty_spots.difference(&beacons_in_row).count()
+}
+
+/// The Manhattan distance between `a` and `b`, which can be more than an i64 can hold
+fn distance(a: Point, b: Point) -> u64 {
+    a.x.abs_diff(b.x).saturating_add(a.y.abs_diff(b.y))
+}
+
+/// Is `pt` inside the keep out zone of any sensor?
+fn is_covered(sensors: &[Point], ranges: &[u64], pt: &Point) -> bool {
+    sensors
+        .iter()
+        .zip(ranges.iter())
+        .any(|(sen, dist)| distance(*sen, *pt) <= *dist)
+}
+
+/// Idea is to check the perimeter of each keep out zone.
+///
+/// There is only one spot the distress beacon can be, so it must sit just outside the
+/// edges of several keep out zones, or against the edge of the search area. The edges
+/// are diagonal lines `y = x + a` and `y = -x + b`, so the only candidates are where
+/// those lines cross each other or the edges, and the corners of the area. Returns the
+/// tuning frequency, or None if every spot in the area is covered.
+pub fn part2(sensors: &[Point], beacons: &[Point], lb: usize, ub: usize) -> Option<u64> {
+    let (lb, ub) = (i64::try_from(lb).ok()?, i64::try_from(ub).ok()?);
+
+    // For each sensor, get its empty range, the range in which there is only one sensor,
+    // right at the edge.
+    let ranges: Vec<u64> = sensors
+        .iter()
+        .zip(beacons.iter())
+        .map(|(sen, beac)| distance(*sen, *beac))
+        .collect();
+
+    // For each sensor, generate the lines one step outside of its diamond. Any line too
+    // far out for an i64 can't cross the search area, so it is left out.
+    let mut rising: Vec<i64> = Vec::new();
+    let mut falling: Vec<i64> = Vec::new();
+    for (sen, dist) in sensors.iter().zip(ranges.iter()) {
+        let Ok(reach) = i64::try_from(dist.saturating_add(1)) else {
+            continue;
+        };
+        if let Some(a) = sen.y.checked_sub(sen.x) {
+            rising.extend(
+                [a.checked_add(reach), a.checked_sub(reach)]
+                    .into_iter()
+                    .flatten(),
+            );
+        }
+        if let Some(b) = sen.y.checked_add(sen.x) {
+            falling.extend(
+                [b.checked_add(reach), b.checked_sub(reach)]
+                    .into_iter()
+                    .flatten(),
+            );
+        }
+    }
+
+    let crossings = rising
+        .iter()
+        .cartesian_product(falling.iter())
+        // Lines only cross on a grid point when a and b have the same parity
+        .filter(|(a, b)| (*b - *a) % 2 == 0)
+        .map(|(a, b)| Point {
+            x: (b - a) / 2,
+            y: (a + b) / 2,
+        });
+    // Where each line meets the rows and columns at the edges of the area
+    let on_edges = [lb, ub].into_iter().flat_map(|edge| {
+        let rising_ends = rising.iter().flat_map(move |&a| {
+            [
+                edge.checked_add(a).map(|y| Point { x: edge, y }),
+                edge.checked_sub(a).map(|x| Point { x, y: edge }),
+            ]
+        });
+        let falling_ends = falling.iter().flat_map(move |&b| {
+            [
+                b.checked_sub(edge).map(|y| Point { x: edge, y }),
+                b.checked_sub(edge).map(|x| Point { x, y: edge }),
+            ]
+        });
+        rising_ends.chain(falling_ends).flatten()
+    });
+    let corners = [lb, ub]
+        .into_iter()
+        .cartesian_product([lb, ub])
+        .map(|(x, y)| Point { x, y });
+
+    let beacon = crossings
+        .chain(on_edges)
+        .chain(corners)
+        .filter(|pt| (lb..=ub).contains(&pt.x) && (lb..=ub).contains(&pt.y))
+        .find(|pt| !is_covered(sensors, &ranges, pt))?;
+
+    // The tuning frequency is 4_000_000*x + y
+    (beacon.x as u64)
+        .checked_mul(4_000_000)?
+        .checked_add(beacon.y as u64)
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    /// The sensors, and the beacon closest to each one
+    type Input = (Vec<Point>, Vec<Point>);
+    type Answer = String;
+
+    fn parse(input_str: &str) -> Result<Self::Input, ParseError> {
+        parse(input_str)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer {
+        part1(&input.0, &input.1, 2000000).to_string()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer {
+        part2(&input.0, &input.1, 0, 4_000_000).map_or_else(
+            || "no spot for the distress beacon".to_string(),
+            |f| f.to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_line() {
+        let input_str = [
+            "Sensor at x=2793338, y=1910659: closest beacon is at x=2504930, y=2301197",
+            "Sensor at x=2887961, y=129550: closest beacon is at x=2745008, y=-872454",
+        ];
+        let want = [
+            (
+                Point {
+                    x: 2793338,
+                    y: 1910659,
+                },
+                Point {
+                    x: 2504930,
+                    y: 2301197,
+                },
+            ),
+            (
+                Point {
+                    x: 2887961,
+                    y: 129550,
+                },
+                Point {
+                    x: 2745008,
+                    y: -872454,
+                },
+            ),
+        ];
+        for (ins, w) in input_str.iter().zip(want.iter()) {
+            let got = parse_line(ins).unwrap();
+            assert_eq!(*w, got);
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input_str = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10
+Sensor at x=13, y=2: closest beacon is at x=15, y=3";
+        let got = parse(input_str).map_err(|e| (e.day, e.line));
+        assert_eq!(Err((15, 2)), got);
+
+        let input_str = "Sensor at x=2, y=18: closest beacon is at x=-2, y=99999999999999999999";
+        let got = parse(input_str).map_err(|e| e.line);
+        assert_eq!(Err(1), got);
+
+        let got = parse("").map_err(|e| e.expected);
+        assert_eq!(Err("at least one sensor".to_string()), got);
+    }
+
+    #[test]
+    fn test_part1() {
+        let input_str = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3";
+
+        let (sensors, beacons) = parse(input_str).unwrap();
+
+        let want = 26;
+        let got = part1(&sensors, &beacons, 10);
+        assert_eq!(want, got);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input_str = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3";
+
+        let (sensors, beacons) = parse(input_str).unwrap();
+
+        let want = Some(56000011);
+        let got = part2(&sensors, &beacons, 0, 20);
+        assert_eq!(want, got);
+
+        // Every spot is covered, and only a corner or an edge is left open
+        let (sensors, beacons) =
+            parse("Sensor at x=2000000, y=2000000: closest beacon is at x=9000000, y=2000000")
+                .unwrap();
+        assert_eq!(None, part2(&sensors, &beacons, 0, 4_000_000));
+        let (sensors, beacons) =
+            parse("Sensor at x=9, y=9: closest beacon is at x=9, y=30").unwrap();
+        assert_eq!(Some(20 * 4_000_000 + 20), part2(&sensors, &beacons, 0, 20));
+        let (sensors, beacons) = parse(
+            "Sensor at x=17, y=5: closest beacon is at x=17, y=18
+Sensor at x=0, y=3: closest beacon is at x=4, y=9
+Sensor at x=8, y=18: closest beacon is at x=10, y=32",
+        )
+        .unwrap();
+        assert_eq!(Some(8 * 4_000_000), part2(&sensors, &beacons, 0, 20));
+
+        // Sensors too far out to reach with an i64 are not a problem
+        let far = i64::MAX - 1;
+        let input_str = format!(
+            "Sensor at x={far}, y=-{far}: closest beacon is at x=-{far}, y={far}
+Sensor at x=9, y=9: closest beacon is at x=9, y=30"
+        );
+        let (sensors, beacons) = parse(&input_str).unwrap();
+        assert_eq!(None, part2(&sensors, &beacons, 0, 20));
+    }
+}
@@ -0,0 +1,91 @@
+use std::fmt;
+
+/// Why a day's puzzle input could not be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The day whose parser rejected the input
+    pub day: u8,
+    /// The 1-based line number the offending text is on
+    pub line: usize,
+    /// The text that could not be parsed
+    pub text: String,
+    /// What should have been there instead
+    pub expected: String,
+}
+
+impl ParseError {
+    /// An error for `text` that has not been placed on a line yet. Parsers for a single
+    /// item use this, and the caller that knows the line number fills it in with
+    /// [`ParseError::at_line`].
+    pub fn new(day: u8, text: &str, expected: impl Into<String>) -> ParseError {
+        ParseError {
+            day,
+            line: 0,
+            text: text.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    pub fn at_line(self, line: usize) -> ParseError {
+        ParseError { line, ..self }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Day {}, line {}: expected {}, found {:?}",
+            self.day, self.line, self.expected, self.text
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parse every line of `input_str` with `parse_line`, and fill in the line number of the
+/// first line that fails
+pub fn parse_lines<T>(
+    input_str: &str,
+    mut parse_line: impl FnMut(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input_str
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| parse_line(line).map_err(|e| e.at_line(idx + 1)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_lines() {
+        let parse_number = |s: &str| {
+            s.parse::<u8>()
+                .map_err(|_| ParseError::new(1, s, "a number"))
+        };
+
+        let got = parse_lines("1\n2\n3", parse_number);
+        assert_eq!(Ok(vec![1, 2, 3]), got);
+
+        let got = parse_lines("1\n2\nthree\n4", parse_number);
+        let want = ParseError {
+            day: 1,
+            line: 3,
+            text: "three".to_string(),
+            expected: "a number".to_string(),
+        };
+        assert_eq!(Err(want), got);
+    }
+
+    #[test]
+    fn test_display() {
+        let e = ParseError::new(9, "Q 4", "U, D, L, or R").at_line(12);
+        assert_eq!(
+            "Day 9, line 12: expected U, D, L, or R, found \"Q 4\"",
+            e.to_string()
+        );
+    }
+}
@@ -0,0 +1,463 @@
+//! Random puzzle inputs, for stress testing the solutions on inputs much larger than the
+//! real ones.
+//!
+//! Every generator takes a seed, so that an input that breaks something can be made
+//! again, and a size that is roughly the number of lines to write. The output is always
+//! something the day's parser accepts.
+
+use std::collections::HashSet;
+
+/// A small, fast, seeded random number generator (SplitMix64). It is not
+/// cryptographically secure, and only needs to make inputs that look random.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A number from 0 up to but not including `n`
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "Cannot pick a number below 0");
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// A number from `lo` to `hi`, inclusive
+    pub fn range(&mut self, lo: i64, hi: i64) -> i64 {
+        assert!(lo <= hi, "Empty range {}..={}", lo, hi);
+        lo + (self.next_u64() % (hi - lo + 1) as u64) as i64
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    /// Shuffle `items` in place, with every order equally likely
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for idx in (1..items.len()).rev() {
+            items.swap(idx, self.below(idx + 1));
+        }
+    }
+}
+
+/// Every day that has a generator
+pub const GENERATED_DAYS: &[u8] = &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 14, 15];
+
+/// A random input for `day`, or None if there is no generator for it
+pub fn generate(day: u8, seed: u64, size: usize) -> Option<String> {
+    let rng = &mut Rng::new(seed);
+    let size = size.max(1);
+    let input_str = match day {
+        1 => day01(rng, size),
+        2 => day02(rng, size),
+        3 => day03(rng, size),
+        4 => day04(rng, size),
+        5 => day05(rng, size),
+        6 => day06(rng, size),
+        7 => day07(rng, size),
+        8 => day08(rng, size),
+        9 => day09(rng, size),
+        10 => day10(rng, size),
+        14 => day14(rng, size),
+        15 => day15(rng, size),
+        _ => return None,
+    };
+    Some(input_str)
+}
+
+const LOWERCASE: &str = "abcdefghijklmnopqrstuvwxyz";
+const LETTERS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// A lowercase name of one to `max_len` letters
+fn name(rng: &mut Rng, max_len: usize) -> String {
+    let letters: Vec<char> = LOWERCASE.chars().collect();
+    (0..=rng.below(max_len))
+        .map(|_| *rng.choose(&letters))
+        .collect()
+}
+
+/// `size` groups of calories, separated by blank lines
+fn day01(rng: &mut Rng, size: usize) -> String {
+    let groups: Vec<String> = (0..size)
+        .map(|_| {
+            let n_items = rng.range(1, 6);
+            (0..n_items)
+                .map(|_| rng.range(1000, 60000).to_string())
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .collect();
+    groups.join("\n\n")
+}
+
+/// `size` rounds like `A X`
+fn day02(rng: &mut Rng, size: usize) -> String {
+    let lines: Vec<String> = (0..size)
+        .map(|_| {
+            format!(
+                "{} {}",
+                rng.choose(&['A', 'B', 'C']),
+                rng.choose(&['X', 'Y', 'Z'])
+            )
+        })
+        .collect();
+    lines.join("\n")
+}
+
+/// One rucksack of a group, made from `pool` and holding `badge`. Exactly one item is
+/// in both compartments.
+fn rucksack(rng: &mut Rng, pool: &[char], badge: char) -> String {
+    let mut pool = pool.to_vec();
+    rng.shuffle(&mut pool);
+
+    let shared = if rng.below(4) == 0 {
+        badge
+    } else {
+        pool.pop().expect("The pool is never empty")
+    };
+    let half_len = rng.range(2, 7) as usize;
+
+    let mut first = vec![shared];
+    if shared != badge {
+        first.push(badge);
+    }
+    let mut second = vec![shared];
+    for half in [&mut first, &mut second] {
+        while half.len() < half_len {
+            half.push(
+                pool.pop()
+                    .expect("The pool has enough letters for both halves"),
+            );
+        }
+        rng.shuffle(half);
+    }
+    first.into_iter().chain(second).collect()
+}
+
+/// Groups of three rucksacks, about `size` lines in all. The three rucksacks of a group
+/// only have their badge in common.
+fn day03(rng: &mut Rng, size: usize) -> String {
+    let letters: Vec<char> = LETTERS.chars().collect();
+    let mut lines = Vec::new();
+    for _ in 0..size.div_ceil(3) {
+        let badge = *rng.choose(&letters);
+        let mut others: Vec<char> = letters.iter().copied().filter(|&c| c != badge).collect();
+        rng.shuffle(&mut others);
+        // Each elf draws from its own third of the other letters
+        for pool in others.chunks(others.len() / 3).take(3) {
+            lines.push(rucksack(rng, pool, badge));
+        }
+    }
+    lines.join("\n")
+}
+
+/// `size` pairs of sections like `2-4,6-8`
+fn day04(rng: &mut Rng, size: usize) -> String {
+    let mut section = || {
+        let start = rng.range(1, 99);
+        let end = rng.range(start, 99);
+        format!("{}-{}", start, end)
+    };
+    let lines: Vec<String> = (0..size)
+        .map(|_| {
+            let first = section();
+            format!("{},{}", first, section())
+        })
+        .collect();
+    lines.join("\n")
+}
+
+/// A drawing of up to nine stacks, then `size` moves that never take more crates than a
+/// stack holds
+fn day05(rng: &mut Rng, size: usize) -> String {
+    let upper: Vec<char> = LETTERS[26..].chars().collect();
+    let n_stacks = rng.range(3, 9) as usize;
+    let mut stacks: Vec<Vec<char>> = (0..n_stacks)
+        .map(|_| (0..rng.range(1, 8)).map(|_| *rng.choose(&upper)).collect())
+        .collect();
+
+    // Draw the stacks from the top down
+    let height = stacks.iter().map(Vec::len).max().unwrap_or_default();
+    let mut lines: Vec<String> = (0..height)
+        .rev()
+        .map(|level| {
+            let row: Vec<String> = stacks
+                .iter()
+                .map(|s| match s.get(level) {
+                    Some(c) => format!("[{}]", c),
+                    None => "   ".to_string(),
+                })
+                .collect();
+            row.join(" ")
+        })
+        .collect();
+    let numbers: Vec<String> = (1..=n_stacks).map(|n| format!(" {} ", n)).collect();
+    lines.push(numbers.join(" "));
+    lines.push(String::new());
+
+    for _ in 0..size {
+        let non_empty: Vec<usize> = (0..n_stacks).filter(|&i| !stacks[i].is_empty()).collect();
+        let from = *rng.choose(&non_empty);
+        let to = (from + 1 + rng.below(n_stacks - 1)) % n_stacks;
+        // Move at most nine crates at a time, like the real inputs
+        let number = rng.range(1, stacks[from].len().min(9) as i64) as usize;
+
+        let keep = stacks[from].len() - number;
+        let moved = stacks[from].split_off(keep);
+        stacks[to].extend(moved);
+        lines.push(format!("move {} from {} to {}", number, from + 1, to + 1));
+    }
+    lines.join("\n")
+}
+
+/// A datastream of `size` characters that cannot hold a start-of-message marker,
+/// followed by one that ends the stream
+fn day06(rng: &mut Rng, size: usize) -> String {
+    // Thirteen letters cannot make a start-of-message marker
+    let few: Vec<char> = LOWERCASE[..13].chars().collect();
+    let mut stream: Vec<char> = (0..size).map(|_| *rng.choose(&few)).collect();
+
+    // Start the marker with the last letter of the stream, so that no window that
+    // straddles the two is all different
+    let last = *stream.last().expect("The stream is never empty");
+    let mut marker: Vec<char> = LOWERCASE.chars().filter(|&c| c != last).collect();
+    rng.shuffle(&mut marker);
+    stream.push(last);
+    stream.extend(&marker[..13]);
+    stream.into_iter().collect()
+}
+
+/// A `$ cd`/`$ ls` transcript of a walk through a filesystem of `size` directories
+fn day07(rng: &mut Rng, size: usize) -> String {
+    // Every directory, as (name, children). The root is 0, and every other directory is
+    // added under one that already exists, so the tree stays shallow
+    let mut dirs: Vec<(String, Vec<usize>)> = vec![("/".to_string(), Vec::new())];
+    for idx in 1..size {
+        let parent = rng.below(idx);
+        let taken: HashSet<&str> = dirs[parent].1.iter().map(|&c| dirs[c].0.as_str()).collect();
+        let mut dir_name = name(rng, 6);
+        while taken.contains(dir_name.as_str()) {
+            dir_name.push(*rng.choose(&['a', 'b', 'c']));
+        }
+        dirs.push((dir_name, Vec::new()));
+        dirs[parent].1.push(idx);
+    }
+
+    let mut lines = vec!["$ cd /".to_string()];
+    walk_dir(rng, &dirs, 0, &mut lines);
+    lines.join("\n")
+}
+
+/// List directory `idx`, then visit each of its children and come back
+fn walk_dir(rng: &mut Rng, dirs: &[(String, Vec<usize>)], idx: usize, lines: &mut Vec<String>) {
+    let children = &dirs[idx].1;
+    let mut listing: Vec<String> = children
+        .iter()
+        .map(|&c| format!("dir {}", dirs[c].0))
+        .collect();
+
+    let child_names: HashSet<&str> = children.iter().map(|&c| dirs[c].0.as_str()).collect();
+    let mut file_names = HashSet::new();
+    for _ in 0..rng.below(5) {
+        let mut file_name = name(rng, 8);
+        if rng.below(2) == 0 {
+            file_name = format!("{}.{}", file_name, name(rng, 3));
+        }
+        if !child_names.contains(file_name.as_str()) && file_names.insert(file_name.clone()) {
+            listing.push(format!("{} {}", rng.range(1, 300_000), file_name));
+        }
+    }
+    rng.shuffle(&mut listing);
+
+    lines.push("$ ls".to_string());
+    lines.extend(listing);
+    for &child in children {
+        lines.push(format!("$ cd {}", dirs[child].0));
+        walk_dir(rng, dirs, child, lines);
+        lines.push("$ cd ..".to_string());
+    }
+}
+
+/// A `size` by `size` grid of tree heights
+fn day08(rng: &mut Rng, size: usize) -> String {
+    let lines: Vec<String> = (0..size)
+        .map(|_| (0..size).map(|_| rng.range(0, 9).to_string()).collect())
+        .collect();
+    lines.join("\n")
+}
+
+/// `size` moves of the head of the rope, like `R 4`
+fn day09(rng: &mut Rng, size: usize) -> String {
+    let lines: Vec<String> = (0..size)
+        .map(|_| format!("{} {}", rng.choose(&['U', 'D', 'L', 'R']), rng.range(1, 20)))
+        .collect();
+    lines.join("\n")
+}
+
+/// `size` CPU instructions
+fn day10(rng: &mut Rng, size: usize) -> String {
+    let lines: Vec<String> = (0..size)
+        .map(|_| match rng.below(3) {
+            0 => "noop".to_string(),
+            _ => format!("addx {}", rng.range(-20, 20)),
+        })
+        .collect();
+    lines.join("\n")
+}
+
+/// `size` paths of rock, each turning a corner up to three times.
+///
+/// Every rock is strictly inside the cone below the source of the sand at 500,0, where
+/// `|x - 500| < y`. Sand can only pile up as high as the source on rock at both edges
+/// of that cone, so the sand always reaches the abyss in part 1. The rock goes no deeper
+/// than day 14 allows, so that the cone never reaches a negative `x`.
+fn day14(rng: &mut Rng, size: usize) -> String {
+    let depth = (10 + size / 4).min(crate::day14::MAX_DEPTH as usize) as i64;
+    let lines: Vec<String> = (0..size)
+        .map(|_| {
+            let mut y = rng.range(2, depth);
+            let mut x = rng.range(500 - (y - 1), 500 + (y - 1));
+            let mut corners = vec![format!("{},{}", x, y)];
+            for turn in 0..rng.range(1, 4) {
+                let step = rng.range(-8, 8);
+                if turn % 2 == 0 {
+                    x = (x + step).clamp(500 - (y - 1), 500 + (y - 1));
+                } else {
+                    y = (y + step).clamp((x - 500).abs() + 1, depth);
+                }
+                corners.push(format!("{},{}", x, y));
+            }
+            corners.join(" -> ")
+        })
+        .collect();
+    lines.join("\n")
+}
+
+/// `size` sensors, each with its closest beacon.
+///
+/// The sensors are scattered, so they leave plenty of room for the distress beacon. Part
+/// 2 expects exactly one gap in the search area, so on these it answers with just one of
+/// the many spots it could be.
+fn day15(rng: &mut Rng, size: usize) -> String {
+    let lines: Vec<String> = (0..size)
+        .map(|_| {
+            let (sx, sy) = (rng.range(0, 4_000_000), rng.range(0, 4_000_000));
+            let (bx, by) = (
+                sx + rng.range(-500_000, 500_000),
+                sy + rng.range(-500_000, 500_000),
+            );
+            format!(
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+                sx, sy, bx, by
+            )
+        })
+        .collect();
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::find_day;
+    use crate::{
+        day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day14, day15,
+    };
+
+    #[test]
+    fn test_rng() {
+        let mut rng = Rng::new(7);
+        let first: Vec<u64> = (0..5).map(|_| rng.next_u64()).collect();
+        let mut again = Rng::new(7);
+        assert_eq!(first, (0..5).map(|_| again.next_u64()).collect::<Vec<_>>());
+
+        for _ in 0..1000 {
+            assert!((-3..=3).contains(&rng.range(-3, 3)));
+            assert!(rng.below(10) < 10);
+        }
+
+        let mut items: Vec<u8> = (0..20).collect();
+        rng.shuffle(&mut items);
+        items.sort_unstable();
+        assert_eq!((0..20).collect::<Vec<_>>(), items);
+    }
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(8, 1, 10), generate(8, 1, 10));
+        assert_ne!(generate(8, 1, 10), generate(8, 2, 10));
+        assert_eq!(None, generate(11, 1, 10));
+        for &day in GENERATED_DAYS {
+            assert!(find_day(day).is_some(), "Day {} is not in DAYS", day);
+            assert!(
+                generate(day, 1, 10).is_some(),
+                "Day {} has no generator",
+                day
+            );
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for seed in 0..5 {
+            let gen = |day| generate(day, seed, 40).unwrap();
+
+            assert_eq!(40, day01::parse(&gen(1)).unwrap().len());
+            assert_eq!(40, day02::parse_input_part1(&gen(2)).unwrap().len());
+            assert_eq!(42, day03::parse_input(&gen(3)).unwrap().len());
+            assert_eq!(40, day04::parse(&gen(4)).unwrap().len());
+            assert_eq!(40, day05::parse(&gen(5)).unwrap().1.len());
+            assert_eq!(54, day06::parse(&gen(6)).len());
+            assert_eq!((40, 40), day08::parse(&gen(8)).unwrap().dim());
+            assert_eq!(40, day09::parse(&gen(9)).unwrap().len());
+            assert_eq!(40, day10::parse(&gen(10)).unwrap().len());
+            assert!(!day14::parse(&gen(14)).unwrap().is_empty());
+            assert_eq!(40, day15::parse(&gen(15)).unwrap().0.len());
+
+            assert_eq!(40, day07::parse(&gen(7)).unwrap().dir_sizes().len());
+        }
+
+        // Large enough that the rock would go deeper than the source is far from x = 0
+        for seed in 0..3 {
+            let walls = day14::parse(&generate(14, seed, 8000).unwrap()).unwrap();
+            assert!(walls.iter().all(|p| p.y <= day14::MAX_DEPTH));
+        }
+    }
+
+    #[test]
+    fn test_solve_generated() {
+        // Day 15 is too slow to solve in a test, and part 2 has no answer on these inputs
+        for &day in GENERATED_DAYS.iter().filter(|&&d| d != 15) {
+            let input_str = generate(day, 3, 60).unwrap();
+            assert!(
+                find_day(day).unwrap().solve(&input_str, None).is_ok(),
+                "Day {}",
+                day
+            );
+        }
+
+        // Every rucksack and every group has exactly one item in common
+        let packs = day03::parse_input(&generate(3, 3, 60).unwrap()).unwrap();
+        for (first, second) in &packs {
+            assert_eq!(1, first.intersection(second).count());
+        }
+
+        // Part 2 of day 15 finds a spot, even with more than one to choose from
+        let (sensors, beacons) = day15::parse(&generate(15, 3, 60).unwrap()).unwrap();
+        assert!(day15::part2(&sensors, &beacons, 0, 4_000_000).is_some());
+
+        // The start-of-message marker is at the very end
+        let stream = day06::parse(&generate(6, 3, 60).unwrap());
+        assert_eq!(74, day06::solve(&stream, 14));
+    }
+}
@@ -0,0 +1,196 @@
+//! Integer points, directions and distances on an unbounded plane.
+//!
+//! Every day uses the same axes as the puzzle drawings: `x` grows to the right and `y`
+//! grows downwards, so [`Dir::Up`] takes one away from `y`. This matches the `(row, col)`
+//! indexing of [`crate::grid::Grid`], with `y` as the row and `x` as the column.
+
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::{Add, Sub};
+
+/// An integer type that points can be made of
+pub trait Coord:
+    Copy + Debug + Default + Eq + Ord + Hash + Add<Output = Self> + Sub<Output = Self>
+{
+    /// `|self - other|`, which cannot overflow or go negative for unsigned types
+    fn abs_diff(self, other: Self) -> Self;
+
+    /// Move one unit in the direction of the sign of `delta`, or not at all if it is 0.
+    /// None if that goes past the smallest or largest value, like below 0 when unsigned.
+    fn step(self, delta: i8) -> Option<Self>;
+}
+
+macro_rules! impl_coord {
+    ($($t:ty),*) => {
+        $(
+            impl Coord for $t {
+                fn abs_diff(self, other: Self) -> Self {
+                    if self > other {
+                        self - other
+                    } else {
+                        other - self
+                    }
+                }
+
+                fn step(self, delta: i8) -> Option<Self> {
+                    match delta.signum() {
+                        -1 => self.checked_sub(1),
+                        1 => self.checked_add(1),
+                        _ => Some(self),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_coord!(i16, i32, i64, isize, u16, u32, u64, usize);
+
+/// A point on the plane, or the vector between two points
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, PartialOrd, Ord)]
+pub struct Point<T: Coord = i64> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Coord> Point<T> {
+    pub fn new(x: T, y: T) -> Point<T> {
+        Point { x, y }
+    }
+
+    /// The number of steps between two points when moving only along the axes
+    pub fn manhattan(self, other: Point<T>) -> T {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The number of steps between two points when diagonal moves are allowed too
+    pub fn chebyshev(self, other: Point<T>) -> T {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// Move one unit in direction `d`, or None if the coordinates can't go that far
+    pub fn step(self, d: Dir) -> Option<Point<T>> {
+        let (dx, dy) = d.delta();
+        Some(Point {
+            x: self.x.step(dx)?,
+            y: self.y.step(dy)?,
+        })
+    }
+}
+
+impl<T: Coord> Add for Point<T> {
+    type Output = Point<T>;
+
+    fn add(self, other: Point<T>) -> Point<T> {
+        Point {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+impl<T: Coord> Sub for Point<T> {
+    type Output = Point<T>;
+
+    fn sub(self, other: Point<T>) -> Point<T> {
+        Point {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+/// One of the four directions along the axes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir {
+    /// Every direction, clockwise from up
+    pub const ALL: [Dir; 4] = [Dir::Up, Dir::Right, Dir::Down, Dir::Left];
+
+    /// The change in `(x, y)` of one step in this direction
+    pub fn delta(self) -> (i8, i8) {
+        match self {
+            Dir::Up => (0, -1),
+            Dir::Right => (1, 0),
+            Dir::Down => (0, 1),
+            Dir::Left => (-1, 0),
+        }
+    }
+
+    /// A quarter turn clockwise
+    pub fn turn_right(self) -> Dir {
+        match self {
+            Dir::Up => Dir::Right,
+            Dir::Right => Dir::Down,
+            Dir::Down => Dir::Left,
+            Dir::Left => Dir::Up,
+        }
+    }
+
+    /// A quarter turn anticlockwise
+    pub fn turn_left(self) -> Dir {
+        self.turn_right().turn_right().turn_right()
+    }
+
+    /// A half turn
+    pub fn reverse(self) -> Dir {
+        self.turn_right().turn_right()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distances() {
+        let p: Point = Point::new(8, 7);
+        let q = Point::new(2, 10);
+        assert_eq!(9, p.manhattan(q));
+        assert_eq!(6, p.chebyshev(q));
+        assert_eq!(Point::new(6, -3), p - q);
+        assert_eq!(p, (p - q) + q);
+
+        // Unsigned points never go negative
+        let p: Point<u16> = Point::new(498, 4);
+        let q = Point::new(502, 9);
+        assert_eq!(9, p.manhattan(q));
+        assert_eq!(5, q.chebyshev(p));
+    }
+
+    #[test]
+    fn test_step() {
+        let p: Point<i16> = Point::new(0, 0);
+        assert_eq!(Some(Point::new(0, -1)), p.step(Dir::Up));
+        assert_eq!(Some(Point::new(1, 0)), p.step(Dir::Right));
+        assert_eq!(Some(Point::new(0, 1)), p.step(Dir::Down));
+        assert_eq!(Some(Point::new(-1, 0)), p.step(Dir::Left));
+
+        let p: Point<u16> = Point::new(500, 0);
+        let got = p.step(Dir::Down).and_then(|p| p.step(Dir::Left));
+        assert_eq!(Some(Point::new(499, 1)), got);
+
+        // Unsigned points stop at 0, and no point goes past the largest value
+        assert_eq!(None, p.step(Dir::Up));
+        assert_eq!(None, Point::new(0u16, 4).step(Dir::Left));
+        assert_eq!(None, Point::new(i16::MAX, 0).step(Dir::Right));
+        assert_eq!(Some(7u16), 7.step(0));
+    }
+
+    #[test]
+    fn test_turns() {
+        for (d, right) in Dir::ALL.iter().zip(Dir::ALL.iter().cycle().skip(1)) {
+            assert_eq!(*right, d.turn_right());
+            assert_eq!(*d, right.turn_left());
+            assert_eq!(*d, d.reverse().reverse());
+            assert_ne!(*d, d.reverse());
+        }
+        assert_eq!(Dir::Down, Dir::Up.reverse());
+    }
+}
@@ -0,0 +1,399 @@
+//! A dense 2D map, for the puzzles that are drawn as a rectangle of characters.
+//!
+//! Cells are indexed by `(row, col)` from the top left, the same as [`ndarray`], so a
+//! grid can be handed to code that works on an `Array2` and back without swapping axes.
+
+use std::ops::{Index, IndexMut};
+
+use ndarray::Array2;
+
+use crate::error::ParseError;
+
+/// The steps to the four cells that share an edge with a cell, clockwise from above
+pub const STEPS_4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// The steps to the eight cells that share an edge or a corner with a cell, in row-major
+/// order
+pub const STEPS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// The index one `step` of `(rows, cols)` away from `idx`, if it is inside a grid of
+/// `dim`
+pub fn offset_within(
+    dim: (usize, usize),
+    idx: (usize, usize),
+    step: (isize, isize),
+) -> Option<(usize, usize)> {
+    let ridx = idx.0.checked_add_signed(step.0)?;
+    let cidx = idx.1.checked_add_signed(step.1)?;
+    (ridx < dim.0 && cidx < dim.1).then_some((ridx, cidx))
+}
+
+/// The indices from `idx` to the edge of a grid of `dim`, taking `step` each time. `idx`
+/// itself is not included, so the nearest cell comes first. For anything that isn't a
+/// [`Grid`], like an `Array2`.
+pub fn ray_within(
+    dim: (usize, usize),
+    idx: (usize, usize),
+    step: (isize, isize),
+) -> impl Iterator<Item = (usize, usize)> {
+    assert!(step != (0, 0), "A ray has to go somewhere");
+    std::iter::successors(offset_within(dim, idx, step), move |&next| {
+        offset_within(dim, next, step)
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    nrows: usize,
+    ncols: usize,
+    /// Every cell, one row after another
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A grid of `nrows` by `ncols` cells that all start as `fill`
+    pub fn new(nrows: usize, ncols: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            nrows,
+            ncols,
+            cells: vec![fill; nrows * ncols],
+        }
+    }
+
+    /// A grid from its cells in row-major order, or None if there are not exactly
+    /// `nrows * ncols` of them
+    pub fn from_vec(nrows: usize, ncols: usize, cells: Vec<T>) -> Option<Grid<T>> {
+        (cells.len() == nrows * ncols).then_some(Grid {
+            nrows,
+            ncols,
+            cells,
+        })
+    }
+
+    /// Parse a rectangular map with one cell per character. `to_cell` turns each
+    /// character into a cell, or returns None if the character does not belong on the
+    /// map, and `expected` describes the characters that do for the error message.
+    pub fn parse(
+        day: u8,
+        input_str: &str,
+        expected: &str,
+        mut to_cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut cells = Vec::new();
+        let mut nrows = 0;
+        let mut ncols = 0;
+        for (ridx, line) in input_str.lines().enumerate() {
+            let before = cells.len();
+            for (cidx, c) in line.chars().enumerate() {
+                let cell = to_cell(c).ok_or_else(|| {
+                    ParseError::new(
+                        day,
+                        &c.to_string(),
+                        format!("{} in column {}", expected, cidx + 1),
+                    )
+                    .at_line(ridx + 1)
+                })?;
+                cells.push(cell);
+            }
+
+            let row_len = cells.len() - before;
+            if ridx == 0 {
+                ncols = row_len;
+            } else if row_len != ncols {
+                // The first column that is there in one row but not in the other
+                let (column, problem) = if row_len > ncols {
+                    (ncols + 1, "is extra")
+                } else {
+                    (row_len + 1, "is missing")
+                };
+                return Err(ParseError::new(
+                    day,
+                    line,
+                    format!(
+                        "a row of {} characters, like the first, but column {} {}",
+                        ncols, column, problem
+                    ),
+                )
+                .at_line(ridx + 1));
+            }
+            nrows += 1;
+        }
+
+        if cells.is_empty() {
+            return Err(ParseError::new(day, input_str, "at least one row of the map").at_line(1));
+        }
+        Ok(Grid {
+            nrows,
+            ncols,
+            cells,
+        })
+    }
+
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    /// `(nrows, ncols)`, like [`Array2::dim`]
+    pub fn dim(&self) -> (usize, usize) {
+        (self.nrows, self.ncols)
+    }
+
+    pub fn in_bounds(&self, idx: (usize, usize)) -> bool {
+        idx.0 < self.nrows && idx.1 < self.ncols
+    }
+
+    pub fn get(&self, idx: (usize, usize)) -> Option<&T> {
+        self.in_bounds(idx)
+            .then(|| &self.cells[idx.0 * self.ncols + idx.1])
+    }
+
+    pub fn get_mut(&mut self, idx: (usize, usize)) -> Option<&mut T> {
+        if self.in_bounds(idx) {
+            Some(&mut self.cells[idx.0 * self.ncols + idx.1])
+        } else {
+            None
+        }
+    }
+
+    /// Every cell, one row after another
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// Every index, one row after another
+    pub fn indices(&self) -> impl Iterator<Item = (usize, usize)> {
+        let ncols = self.ncols;
+        (0..self.nrows).flat_map(move |ridx| (0..ncols).map(move |cidx| (ridx, cidx)))
+    }
+
+    /// Every cell with its index, one row after another
+    pub fn indexed_iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.indices().zip(self.cells.iter())
+    }
+
+    /// A grid of the same shape with `f` applied to every cell
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            nrows: self.nrows,
+            ncols: self.ncols,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// The index one `step` of `(rows, cols)` away from `idx`, if it is on the grid
+    pub fn offset(&self, idx: (usize, usize), step: (isize, isize)) -> Option<(usize, usize)> {
+        offset_within(self.dim(), idx, step)
+    }
+
+    /// The indices of the cells that share an edge with `idx`, clockwise from above
+    pub fn neighbors4(&self, idx: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        STEPS_4
+            .iter()
+            .filter_map(move |&step| self.offset(idx, step))
+    }
+
+    /// The indices of the cells that share an edge or a corner with `idx`, in row-major
+    /// order
+    pub fn neighbors8(&self, idx: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        STEPS_8
+            .iter()
+            .filter_map(move |&step| self.offset(idx, step))
+    }
+
+    /// The indices from `idx` to the edge of the grid, taking `step` each time. `idx`
+    /// itself is not included, so the nearest cell comes first.
+    pub fn ray(
+        &self,
+        idx: (usize, usize),
+        step: (isize, isize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ray_within(self.dim(), idx, step)
+    }
+
+    /// The indices directly above `idx`, nearest first
+    pub fn above(&self, idx: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.ray(idx, (-1, 0))
+    }
+
+    /// The indices directly below `idx`, nearest first
+    pub fn below(&self, idx: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.ray(idx, (1, 0))
+    }
+
+    /// The indices directly to the left of `idx`, nearest first
+    pub fn left(&self, idx: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.ray(idx, (0, -1))
+    }
+
+    /// The indices directly to the right of `idx`, nearest first
+    pub fn right(&self, idx: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.ray(idx, (0, 1))
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, idx: (usize, usize)) -> &T {
+        self.get(idx).unwrap_or_else(|| {
+            panic!(
+                "Index {:?} is outside a grid of {:?}",
+                idx,
+                (self.nrows, self.ncols)
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, idx: (usize, usize)) -> &mut T {
+        let dim = self.dim();
+        self.get_mut(idx)
+            .unwrap_or_else(|| panic!("Index {:?} is outside a grid of {:?}", idx, dim))
+    }
+}
+
+impl<T> From<Array2<T>> for Grid<T> {
+    fn from(arr: Array2<T>) -> Grid<T> {
+        let (nrows, ncols) = arr.dim();
+        Grid {
+            nrows,
+            ncols,
+            cells: arr.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> From<Grid<T>> for Array2<T> {
+    fn from(grid: Grid<T>) -> Array2<T> {
+        Array2::from_shape_vec(grid.dim(), grid.cells)
+            .expect("A grid always has nrows * ncols cells")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::arr2;
+
+    fn example() -> Grid<u8> {
+        Grid::parse(8, "303\n255\n653", "a digit", |c| {
+            c.to_digit(10).map(|d| d as u8)
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let got = example();
+        assert_eq!((3, 3), got.dim());
+        assert_eq!(
+            vec![3, 0, 3, 2, 5, 5, 6, 5, 3],
+            got.iter().copied().collect::<Vec<_>>()
+        );
+        assert_eq!(6, got[(2, 0)]);
+        assert_eq!(None, got.get((3, 0)));
+
+        let walls = Grid::parse(14, "#.\r\n.#\r\n", "'#' or '.'", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })
+        .unwrap();
+        assert_eq!(
+            Grid::from_vec(2, 2, vec![true, false, false, true]),
+            Some(walls)
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let digit = |c: char| c.to_digit(10);
+
+        let got = Grid::parse(8, "303\n2x5", "a digit", digit);
+        let want = ParseError::new(8, "x", "a digit in column 2").at_line(2);
+        assert_eq!(Err(want), got);
+
+        let got = Grid::parse(8, "303\n25\n653", "a digit", digit);
+        let want = ParseError::new(
+            8,
+            "25",
+            "a row of 3 characters, like the first, but column 3 is missing",
+        )
+        .at_line(2);
+        assert_eq!(Err(want), got);
+
+        let got = Grid::parse(8, "303\n255\n6532", "a digit", digit).map_err(|e| e.expected);
+        let want = "a row of 3 characters, like the first, but column 4 is extra";
+        assert_eq!(Err(want.to_string()), got);
+
+        assert!(Grid::parse(8, "", "a digit", digit).is_err());
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = example();
+
+        let got: Vec<_> = grid.neighbors4((0, 0)).collect();
+        assert_eq!(vec![(0, 1), (1, 0)], got);
+        let got: Vec<_> = grid.neighbors4((1, 1)).collect();
+        assert_eq!(vec![(0, 1), (1, 2), (2, 1), (1, 0)], got);
+
+        let got: Vec<_> = grid.neighbors8((2, 2)).collect();
+        assert_eq!(vec![(1, 1), (1, 2), (2, 1)], got);
+        assert_eq!(8, grid.neighbors8((1, 1)).count());
+    }
+
+    #[test]
+    fn test_rays() {
+        let grid = Grid::new(5, 4, 0);
+
+        let got: Vec<_> = grid.above((3, 1)).collect();
+        assert_eq!(vec![(2, 1), (1, 1), (0, 1)], got);
+        let got: Vec<_> = grid.left((3, 1)).collect();
+        assert_eq!(vec![(3, 0)], got);
+        let got: Vec<_> = grid.below((3, 1)).collect();
+        assert_eq!(vec![(4, 1)], got);
+        let got: Vec<_> = grid.right((3, 1)).collect();
+        assert_eq!(vec![(3, 2), (3, 3)], got);
+        assert_eq!(0, grid.right((0, 3)).count());
+
+        let got: Vec<_> = grid.ray((0, 0), (1, 1)).collect();
+        assert_eq!(vec![(1, 1), (2, 2), (3, 3)], got);
+
+        // The same rays work on anything with a shape
+        let got: Vec<_> = ray_within((5, 5), (2, 2), (-1, 1)).collect();
+        assert_eq!(vec![(1, 3), (0, 4)], got);
+        let got: Vec<_> = ray_within((5, 5), (2, 2), (2, 1)).collect();
+        assert_eq!(vec![(4, 3)], got);
+        assert_eq!(0, ray_within((5, 5), (4, 4), (1, 0)).count());
+    }
+
+    #[test]
+    fn test_array2() {
+        let arr = arr2(&[[3, 0, 3], [2, 5, 5], [6, 5, 3]]);
+        let grid = Grid::from(arr.clone());
+        assert_eq!(example(), grid);
+        assert_eq!(arr, Array2::from(grid));
+
+        // A transposed view is not in row-major order in memory
+        let grid = Grid::from(arr.t().to_owned());
+        assert_eq!(2, grid[(0, 1)]);
+    }
+}
@@ -0,0 +1,122 @@
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// The environment variable that moves the default input directory away from `input/`
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where to read a day's puzzle input from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// `dayNN.txt` in `$AOC_INPUT_DIR`, or in `input/` if that is not set
+    Default,
+    /// A file with the input for one day, or a directory of `dayNN.txt` files
+    Path(PathBuf),
+    /// Standard input
+    Stdin,
+}
+
+impl InputSource {
+    /// `-` means standard input, and anything else is a path
+    pub fn from_arg(arg: &str) -> InputSource {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::Path(PathBuf::from(arg))
+        }
+    }
+
+    /// Can this source provide the inputs for several days?
+    pub fn covers_many_days(&self) -> bool {
+        self.dir().is_some()
+    }
+
+    /// The directory the inputs come from, if they come from a directory
+    pub fn dir(&self) -> Option<PathBuf> {
+        match self {
+            InputSource::Default => Some(
+                std::env::var_os(INPUT_DIR_VAR)
+                    .unwrap_or_else(|| "input".into())
+                    .into(),
+            ),
+            InputSource::Path(p) if p.is_dir() => Some(p.clone()),
+            _ => None,
+        }
+    }
+
+    /// The file the input for `day` is read from, or None for standard input
+    pub fn path(&self, day: u8) -> Option<PathBuf> {
+        match (self, self.dir()) {
+            (_, Some(dir)) => Some(day_file(&dir, day)),
+            (InputSource::Path(p), None) => Some(p.clone()),
+            _ => None,
+        }
+    }
+
+    /// Read the whole input for `day`
+    pub fn read(&self, day: u8) -> io::Result<String> {
+        match self.path(day) {
+            Some(path) => std::fs::read_to_string(path),
+            None => {
+                let mut input_str = String::new();
+                io::stdin().read_to_string(&mut input_str)?;
+                Ok(input_str)
+            }
+        }
+    }
+
+    /// Where the input for `day` comes from, for messages
+    pub fn describe(&self, day: u8) -> String {
+        match self.path(day) {
+            Some(path) => path.display().to_string(),
+            None => "standard input".to_string(),
+        }
+    }
+}
+
+/// The conventional name of the input file for `day` inside `dir`
+pub fn day_file(dir: &Path, day: u8) -> PathBuf {
+    dir.join(format!("day{:02}.txt", day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(InputSource::Stdin, InputSource::from_arg("-"));
+        assert_eq!(
+            InputSource::Path(PathBuf::from("other/day05.txt")),
+            InputSource::from_arg("other/day05.txt")
+        );
+    }
+
+    #[test]
+    fn test_day_file() {
+        let want = PathBuf::from("somewhere/day07.txt");
+        assert_eq!(want, day_file(Path::new("somewhere"), 7));
+
+        let want = PathBuf::from("somewhere/day15.txt");
+        assert_eq!(want, day_file(Path::new("somewhere"), 15));
+    }
+
+    #[test]
+    fn test_read_from_directory_and_file() {
+        let dir = std::env::temp_dir().join(format!("aoc_2022_input_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(day_file(&dir, 4), "2-4,6-8").unwrap();
+
+        let from_dir = InputSource::Path(dir.clone());
+        assert!(from_dir.covers_many_days());
+        assert_eq!(Some(dir.clone()), from_dir.dir());
+        assert_eq!("2-4,6-8", from_dir.read(4).unwrap());
+        assert!(from_dir.read(5).is_err());
+
+        let from_file = InputSource::Path(day_file(&dir, 4));
+        assert!(!from_file.covers_many_days());
+        assert_eq!(None, from_file.dir());
+        assert_eq!("2-4,6-8", from_file.read(9).unwrap());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
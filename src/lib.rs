@@ -0,0 +1,150 @@
+//! Solutions to Advent of Code 2022.
+//!
+//! Each day lives in its own module, and implements [`Solution`] so that the runner (and
+//! anything else) can treat every day the same way.
+
+pub mod bench;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day14;
+pub mod day15;
+pub mod error;
+pub mod gen;
+pub mod geom;
+pub mod grid;
+pub mod input;
+pub mod scaffold;
+pub mod verify;
+
+use std::fmt::Display;
+
+use bench::{BenchConfig, DayBench};
+pub use error::ParseError;
+
+/// The common shape of every day's puzzle: parse the input once, then solve both parts
+/// from the parsed input.
+pub trait Solution {
+    /// Which day of the advent calendar this solves
+    const DAY: u8;
+
+    /// What the puzzle input is parsed into
+    type Input;
+
+    /// What both parts return
+    type Answer: Display;
+
+    fn parse(input_str: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> Self::Answer;
+
+    fn part2(input: &Self::Input) -> Self::Answer;
+}
+
+/// Answers to one or both parts, each paired with the number of its part
+pub type Answers = Vec<(u8, String)>;
+
+/// A [`Solution`] with its types erased, so that every day can be kept in one list
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    pub day: u8,
+    solve: fn(&str, Option<u8>) -> Result<Answers, ParseError>,
+    bench: fn(&str, BenchConfig) -> Result<DayBench, ParseError>,
+}
+
+impl Day {
+    pub const fn new<S: Solution>() -> Day {
+        Day {
+            day: S::DAY,
+            solve: solve::<S>,
+            bench: bench::bench::<S>,
+        }
+    }
+
+    /// Parse `input_str`, and return the answer to `part`, or to both parts if `part` is
+    /// None
+    pub fn solve(&self, input_str: &str, part: Option<u8>) -> Result<Answers, ParseError> {
+        (self.solve)(input_str, part)
+    }
+
+    /// Time parsing `input_str` and solving both parts
+    pub fn bench(&self, input_str: &str, config: BenchConfig) -> Result<DayBench, ParseError> {
+        (self.bench)(input_str, config)
+    }
+}
+
+fn solve<S: Solution>(input_str: &str, part: Option<u8>) -> Result<Answers, ParseError> {
+    let input = S::parse(input_str)?;
+    let mut answers = Vec::new();
+    if part.is_none_or(|p| p == 1) {
+        answers.push((1, S::part1(&input).to_string()));
+    }
+    if part.is_none_or(|p| p == 2) {
+        answers.push((2, S::part2(&input).to_string()));
+    }
+    Ok(answers)
+}
+
+/// Every day that has a solution, in order
+pub const DAYS: &[Day] = &[
+    Day::new::<day01::Day01>(),
+    Day::new::<day02::Day02>(),
+    Day::new::<day03::Day03>(),
+    Day::new::<day04::Day04>(),
+    Day::new::<day05::Day05>(),
+    Day::new::<day06::Day06>(),
+    Day::new::<day07::Day07>(),
+    Day::new::<day08::Day08>(),
+    Day::new::<day09::Day09>(),
+    Day::new::<day10::Day10>(),
+    Day::new::<day14::Day14>(),
+    Day::new::<day15::Day15>(),
+];
+
+/// Look up the solution for `day`
+pub fn find_day(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days_in_order() {
+        for (d1, d2) in DAYS.iter().zip(DAYS.iter().skip(1)) {
+            assert!(d1.day < d2.day);
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        let input_str = "1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+        let day = find_day(1).expect("Day 1 is missing");
+        let want = vec![(1, "24000".to_string()), (2, "45000".to_string())];
+        assert_eq!(Ok(want), day.solve(input_str, None));
+
+        let want = vec![(2, "45000".to_string())];
+        assert_eq!(Ok(want), day.solve(input_str, Some(2)));
+    }
+}
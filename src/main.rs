@@ -1,3 +1,570 @@
+//! `aoc` runs the solutions for each day.
+//!
+//! ```shell
+//! aoc run 5            # both parts of day 5
+//! aoc run 5 --part 2   # only part 2 of day 5
+//! aoc run all          # every day that has a solution
+//! aoc bench all        # time parsing and both parts of every day
+//! aoc verify           # check every day against the accepted answers
+//! aoc new-day 11       # start a solution for day 11
+//! aoc gen 8 --size 1000 # a random input for day 8, a thousand lines long
+//! ```
+//!
+//! Each day reads `input/dayNN.txt` unless told otherwise. `--input <PATH>` reads a
+//! file, or a directory of `dayNN.txt` files, and `--input -` reads standard input.
+//! Setting `AOC_INPUT_DIR` changes the default directory.
+//!
+//! `bench` runs each piece `--warmup` times untimed, then `--runs` times timed, and
+//! reports the min, median and p95 in microseconds. `--json` prints the same summary
+//! as JSON instead of a table.
+//!
+//! `verify` compares every answer to the answer key, `answers.txt` in the input
+//! directory unless `--answers <PATH>` says otherwise.
+//!
+//! `new-day` writes `src/dayNN.rs` from a template, adds it to `src/lib.rs` so that the
+//! runner knows about it, and creates an empty `input/dayNN.txt`. It works in the
+//! current directory unless `--root <DIR>` points at the checkout.
+//!
+//! `gen` prints a random input for a day, made from `--seed` (0 by default) and about
+//! `--size` lines long (100 by default).
+
+use std::path::PathBuf;
+
+use aoc_2022::bench::{self, micros, BenchConfig, DayBench, Stats};
+use aoc_2022::input::InputSource;
+use aoc_2022::verify::{AnswerKey, Outcome, ANSWERS_FILE};
+use aoc_2022::{find_day, DAYS};
+use aoc_2022::{gen, scaffold};
+
+const USAGE: &str = "Usage:
+    aoc run <DAY|all> [--part <1|2>] [--input <PATH|->]
+    aoc bench <DAY|all> [--runs <N>] [--warmup <N>] [--json] [--input <PATH|->]
+    aoc verify [DAY|all] [--input <PATH|->] [--answers <PATH>]
+    aoc new-day <DAY> [--root <DIR>]
+    aoc gen <DAY> [--seed <N>] [--size <N>]";
+
+#[derive(Debug, PartialEq, Eq)]
+enum Command {
+    Run {
+        days: Vec<u8>,
+        part: Option<u8>,
+        input: InputSource,
+    },
+    Bench {
+        days: Vec<u8>,
+        config: BenchConfig,
+        json: bool,
+        input: InputSource,
+    },
+    Verify {
+        days: Vec<u8>,
+        input: InputSource,
+        answers: Option<PathBuf>,
+    },
+    NewDay {
+        day: u8,
+        root: PathBuf,
+    },
+    Gen {
+        day: u8,
+        seed: u64,
+        size: usize,
+    },
+}
+
+/// Parse the command line arguments, not including the program name
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    let mut args = args.iter().peekable();
+    let command = match args.next().map(String::as_str) {
+        Some(c @ ("run" | "bench" | "verify" | "new-day" | "gen")) => c,
+        Some(other) => return Err(format!("Unknown command '{}'", other)),
+        None => return Err("No command given".to_string()),
+    };
+
+    // `verify` checks every day unless it is given one
+    let all_days = || DAYS.iter().map(|d| d.day).collect();
+    let days = match args.peek().map(|s| s.as_str()) {
+        Some(flag) if command == "verify" && flag.starts_with('-') => all_days(),
+        None if command == "verify" => all_days(),
+        // `new-day` is for days that do not have a solution yet, and `gen` can make
+        // inputs for days that do not have one
+        _ if command == "new-day" || command == "gen" => {
+            let day = args
+                .next()
+                .and_then(|s| s.parse().ok())
+                .ok_or(format!("{} must be followed by a day number", command))?;
+            vec![day]
+        }
+        _ => parse_days(args.next())?,
+    };
+
+    let mut part = None;
+    let mut input = InputSource::Default;
+    let mut config = BenchConfig::default();
+    let mut json = false;
+    let mut answers = None;
+    let mut root = PathBuf::from(".");
+    let mut seed = 0;
+    let mut size = 100;
+    while let Some(arg) = args.next() {
+        match (command, arg.as_str()) {
+            ("run", "--part" | "-p") => {
+                let p = args
+                    .next()
+                    .and_then(|s| s.parse::<u8>().ok())
+                    .filter(|p| (1..=2).contains(p))
+                    .ok_or("--part must be followed by 1 or 2")?;
+                part = Some(p);
+            }
+            (_, "--input" | "-i") => {
+                let path = args
+                    .next()
+                    .ok_or("--input must be followed by a path or '-'")?;
+                input = InputSource::from_arg(path);
+            }
+            ("bench", "--runs") => {
+                config.runs = args
+                    .next()
+                    .and_then(|s| s.parse().ok())
+                    .filter(|&n| n > 0)
+                    .ok_or("--runs must be followed by a number above 0")?;
+            }
+            ("bench", "--warmup") => {
+                config.warmup = args
+                    .next()
+                    .and_then(|s| s.parse().ok())
+                    .ok_or("--warmup must be followed by a number")?;
+            }
+            ("bench", "--json") => json = true,
+            ("verify", "--answers") => {
+                let path = args.next().ok_or("--answers must be followed by a path")?;
+                answers = Some(PathBuf::from(path));
+            }
+            ("new-day", "--root") => {
+                let path = args
+                    .next()
+                    .ok_or("--root must be followed by a directory")?;
+                root = PathBuf::from(path);
+            }
+            ("gen", "--seed") => {
+                seed = args
+                    .next()
+                    .and_then(|s| s.parse().ok())
+                    .ok_or("--seed must be followed by a number")?;
+            }
+            ("gen", "--size") => {
+                size = args
+                    .next()
+                    .and_then(|s| s.parse().ok())
+                    .filter(|&n| n > 0)
+                    .ok_or("--size must be followed by a number above 0")?;
+            }
+            (_, other) => return Err(format!("Unexpected argument '{}'", other)),
+        }
+    }
+
+    if days.len() > 1 && !input.covers_many_days() {
+        return Err("--input needs to be a directory when running more than one day".to_string());
+    }
+
+    match command {
+        "run" => Ok(Command::Run { days, part, input }),
+        "bench" => Ok(Command::Bench {
+            days,
+            config,
+            json,
+            input,
+        }),
+        "new-day" => Ok(Command::NewDay { day: days[0], root }),
+        "gen" => {
+            if !gen::GENERATED_DAYS.contains(&days[0]) {
+                return Err(format!("Day {} has no input generator", days[0]));
+            }
+            Ok(Command::Gen {
+                day: days[0],
+                seed,
+                size,
+            })
+        }
+        _ => {
+            if answers.is_none() && input.dir().is_none() {
+                return Err("--answers is needed when --input is not a directory".to_string());
+            }
+            Ok(Command::Verify {
+                days,
+                input,
+                answers,
+            })
+        }
+    }
+}
+
+/// `all`, or the number of a day that has a solution
+fn parse_days(arg: Option<&String>) -> Result<Vec<u8>, String> {
+    match arg.map(String::as_str) {
+        Some("all") => Ok(DAYS.iter().map(|d| d.day).collect()),
+        Some(day_s) => {
+            let day: u8 = day_s
+                .parse()
+                .map_err(|_| format!("'{}' is not a day number", day_s))?;
+            if find_day(day).is_none() {
+                return Err(format!("Day {} has no solution", day));
+            }
+            Ok(vec![day])
+        }
+        None => Err("No day given".to_string()),
+    }
+}
+
+/// Read the input for `day`
+fn read_input(day: u8, input: &InputSource) -> Result<String, String> {
+    input.read(day).map_err(|e| {
+        format!(
+            "Failed to read day {} input from {}: {}",
+            day,
+            input.describe(day),
+            e
+        )
+    })
+}
+
+/// Read the input for `day`, and print the answers to the parts that were asked for
+fn run_day(day: u8, part: Option<u8>, input: &InputSource) -> Result<(), String> {
+    let solution = find_day(day).ok_or(format!("Day {} has no solution", day))?;
+
+    let input_str = read_input(day, input)?;
+
+    let answers = solution
+        .solve(&input_str, part)
+        .map_err(|e| e.to_string())?;
+
+    println!("Day {}", day);
+    for (p, answer) in answers {
+        // Some answers are drawn over several lines, so start those on their own line
+        if answer.contains('\n') {
+            println!("Part {} result:\n{}", p, answer);
+        } else {
+            println!("Part {} result: {}", p, answer);
+        }
+    }
+    Ok(())
+}
+
+/// Time every day in `days`
+fn bench_days(
+    days: &[u8],
+    config: BenchConfig,
+    input: &InputSource,
+) -> Result<Vec<DayBench>, String> {
+    days.iter()
+        .map(|&day| {
+            let solution = find_day(day).ok_or(format!("Day {} has no solution", day))?;
+            let input_str = read_input(day, input)?;
+            solution
+                .bench(&input_str, config)
+                .map_err(|e| e.to_string())
+        })
+        .collect()
+}
+
+/// Lay the timings out as a table
+fn print_bench_table(results: &[DayBench]) {
+    println!(
+        "{:>3}  {:<6}{:>14}{:>14}{:>14}",
+        "Day", "Step", "min (µs)", "median (µs)", "p95 (µs)"
+    );
+    for r in results {
+        for (step, stats) in [("parse", r.parse), ("part1", r.part1), ("part2", r.part2)] {
+            let Stats { min, median, p95 } = stats;
+            println!(
+                "{:>3}  {:<6}{:>14.3}{:>14.3}{:>14.3}",
+                r.day,
+                step,
+                micros(min),
+                micros(median),
+                micros(p95)
+            );
+        }
+    }
+}
+
+/// How many parts passed, failed, or had nothing to compare against
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Tally {
+    pass: usize,
+    fail: usize,
+    missing: usize,
+}
+
+/// Check both parts of every day in `days` against `key`, printing each outcome
+fn verify_days(days: &[u8], input: &InputSource, key: &AnswerKey) -> Tally {
+    let mut tally = Tally::default();
+    for &day in days {
+        let Some(solution) = find_day(day) else {
+            continue;
+        };
+
+        let input_str = match read_input(day, input) {
+            Ok(s) => s,
+            Err(e) => {
+                println!("Day {}: missing ({})", day, e);
+                tally.missing += 2;
+                continue;
+            }
+        };
+
+        let answers = match solution.solve(&input_str, None) {
+            Ok(a) => a,
+            Err(e) => {
+                println!("Day {}: FAIL ({})", day, e);
+                tally.fail += 2;
+                continue;
+            }
+        };
+
+        for (part, got) in answers {
+            let outcome = key.check(day, part, &got);
+            match outcome {
+                Outcome::Pass => tally.pass += 1,
+                Outcome::Fail { .. } => tally.fail += 1,
+                Outcome::Missing => tally.missing += 1,
+            }
+            println!("Day {} part {}: {}", day, part, outcome);
+        }
+    }
+    tally
+}
+
+/// Read the answer key from `answers`, or from the input directory
+fn read_answer_key(answers: Option<PathBuf>, input: &InputSource) -> Result<AnswerKey, String> {
+    let path = answers
+        .or_else(|| input.dir().map(|d| d.join(ANSWERS_FILE)))
+        .ok_or("No answer key to read")?;
+    let key_str = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read answer key {}: {}", path.display(), e))?;
+    AnswerKey::parse(&key_str)
+}
+
 fn main() {
-    println!("Hello, world!");
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let command = match parse_args(&args) {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("{}\n{}", e, USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    match command {
+        Command::Run { days, part, input } => {
+            for (idx, day) in days.iter().enumerate() {
+                if idx > 0 {
+                    println!();
+                }
+                if let Err(e) = run_day(*day, part, &input) {
+                    eprintln!("{}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        Command::Bench {
+            days,
+            config,
+            json,
+            input,
+        } => match bench_days(&days, config, &input) {
+            Ok(results) if json => println!("{}", bench::to_json(config, &results)),
+            Ok(results) => print_bench_table(&results),
+            Err(e) => {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        },
+        Command::Verify {
+            days,
+            input,
+            answers,
+        } => {
+            let key = match read_answer_key(answers, &input) {
+                Ok(k) => k,
+                Err(e) => {
+                    eprintln!("{}", e);
+                    std::process::exit(1);
+                }
+            };
+            let tally = verify_days(&days, &input, &key);
+            println!();
+            println!(
+                "{} passed, {} failed, {} missing",
+                tally.pass, tally.fail, tally.missing
+            );
+            if tally.fail > 0 {
+                std::process::exit(1);
+            }
+        }
+        Command::NewDay { day, root } => match scaffold::new_day(&root, day) {
+            Ok(written) => {
+                for path in written {
+                    println!("Wrote {}", path.display());
+                }
+            }
+            Err(e) => {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        },
+        Command::Gen { day, seed, size } => {
+            let input_str = gen::generate(day, seed, size).expect("Checked when parsing args");
+            println!("{}", input_str);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let want = Command::Run {
+            days: vec![5],
+            part: None,
+            input: InputSource::Default,
+        };
+        assert_eq!(Ok(want), parse_args(&args("run 5")));
+
+        let want = Command::Run {
+            days: vec![5],
+            part: Some(2),
+            input: InputSource::Default,
+        };
+        assert_eq!(Ok(want), parse_args(&args("run 5 --part 2")));
+
+        let want = Command::Run {
+            days: DAYS.iter().map(|d| d.day).collect(),
+            part: None,
+            input: InputSource::Default,
+        };
+        assert_eq!(Ok(want), parse_args(&args("run all")));
+
+        let want = Command::Run {
+            days: vec![5],
+            part: Some(1),
+            input: InputSource::Stdin,
+        };
+        assert_eq!(Ok(want), parse_args(&args("run 5 --input - --part 1")));
+
+        let want = Command::Run {
+            days: DAYS.iter().map(|d| d.day).collect(),
+            part: None,
+            input: InputSource::Path("input".into()),
+        };
+        assert_eq!(Ok(want), parse_args(&args("run all --input input")));
+
+        let want = Command::Bench {
+            days: vec![8],
+            config: BenchConfig::default(),
+            json: false,
+            input: InputSource::Default,
+        };
+        assert_eq!(Ok(want), parse_args(&args("bench 8")));
+
+        let want = Command::Bench {
+            days: DAYS.iter().map(|d| d.day).collect(),
+            config: BenchConfig {
+                warmup: 0,
+                runs: 50,
+            },
+            json: true,
+            input: InputSource::Default,
+        };
+        assert_eq!(
+            Ok(want),
+            parse_args(&args("bench all --runs 50 --warmup 0 --json"))
+        );
+
+        let want = Command::Verify {
+            days: DAYS.iter().map(|d| d.day).collect(),
+            input: InputSource::Default,
+            answers: None,
+        };
+        assert_eq!(Ok(want), parse_args(&args("verify")));
+
+        let want = Command::Verify {
+            days: vec![14],
+            input: InputSource::Stdin,
+            answers: Some(PathBuf::from("key.txt")),
+        };
+        assert_eq!(
+            Ok(want),
+            parse_args(&args("verify 14 --input - --answers key.txt"))
+        );
+
+        let want = Command::Verify {
+            days: DAYS.iter().map(|d| d.day).collect(),
+            input: InputSource::Default,
+            answers: Some(PathBuf::from("key.txt")),
+        };
+        assert_eq!(Ok(want), parse_args(&args("verify --answers key.txt")));
+
+        let want = Command::NewDay {
+            day: 11,
+            root: PathBuf::from("."),
+        };
+        assert_eq!(Ok(want), parse_args(&args("new-day 11")));
+
+        let want = Command::NewDay {
+            day: 16,
+            root: PathBuf::from("checkout"),
+        };
+        assert_eq!(Ok(want), parse_args(&args("new-day 16 --root checkout")));
+
+        let want = Command::Gen {
+            day: 7,
+            seed: 0,
+            size: 100,
+        };
+        assert_eq!(Ok(want), parse_args(&args("gen 7")));
+
+        let want = Command::Gen {
+            day: 8,
+            seed: 42,
+            size: 1000,
+        };
+        assert_eq!(Ok(want), parse_args(&args("gen 8 --size 1000 --seed 42")));
+    }
+
+    #[test]
+    fn test_parse_args_errors() {
+        for bad in [
+            "",
+            "walk 5",
+            "run",
+            "run x",
+            "run 26",
+            "run 5 --part 3",
+            "run 5 -x",
+            "run 5 --input",
+            "run all --input -",
+            "run all --input input/day01.txt",
+            "run 5 --json",
+            "bench 5 --part 1",
+            "bench 5 --runs 0",
+            "bench 5 --warmup",
+            "verify 5 --input -",
+            "verify 5 --part 1",
+            "new-day",
+            "new-day eleven",
+            "new-day 11 --part 1",
+            "gen 11",
+            "gen 8 --size 0",
+            "gen 8 --seed -1",
+        ] {
+            assert!(parse_args(&args(bad)).is_err(), "{:?} should fail", bad);
+        }
+    }
 }
@@ -0,0 +1,300 @@
+//! Generating the skeleton of a new day, and registering it with the runner.
+
+use std::path::{Path, PathBuf};
+
+use crate::input::day_file;
+
+/// The module a new day starts from. `NN` is replaced with the zero padded day, and
+/// `DAY_NUMBER` with the plain one. Both parts answer 0 until they are written, so that
+/// `verify`, `run all` and `bench all` keep working with the new day registered.
+const TEMPLATE: &str = r#"use crate::error::{parse_lines, ParseError};
+use crate::Solution;
+
+pub fn parse(input_str: &str) -> Result<Vec<String>, ParseError> {
+    parse_lines(input_str, |line| Ok(line.to_string()))
+}
+
+/// TODO: Day DAY_NUMBER part 1
+pub fn part1(_input: &[String]) -> usize {
+    0
+}
+
+/// TODO: Day DAY_NUMBER part 2
+pub fn part2(_input: &[String]) -> usize {
+    0
+}
+
+pub struct DayNN;
+
+impl Solution for DayNN {
+    const DAY: u8 = DAY_NUMBER;
+    type Input = Vec<String>;
+    type Answer = usize;
+
+    fn parse(input_str: &str) -> Result<Self::Input, ParseError> {
+        parse(input_str)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[ignore = "Needs the example input and answer from the puzzle"]
+    fn test_part1() {
+        let input_str = "";
+        let input = parse(input_str).unwrap();
+        let want = 0;
+        let got = part1(&input);
+        assert_eq!(want, got);
+    }
+
+    #[test]
+    #[ignore = "Needs the example input and answer from the puzzle"]
+    fn test_part2() {
+        let input_str = "";
+        let input = parse(input_str).unwrap();
+        let want = 0;
+        let got = part2(&input);
+        assert_eq!(want, got);
+    }
+}
+"#;
+
+/// The source of a new module for `day`
+pub fn module_source(day: u8) -> String {
+    TEMPLATE
+        .replace("NN", &format!("{:02}", day))
+        .replace("DAY_NUMBER", &day.to_string())
+}
+
+/// Insert `new_line` among the lines that `day_of` gives a day for, keeping them in
+/// order of day
+fn insert_in_order(
+    lines: &mut Vec<String>,
+    new_line: String,
+    day: u8,
+    day_of: impl Fn(&str) -> Option<u8>,
+) -> Result<(), String> {
+    let days: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, line)| day_of(line).map(|d| (idx, d)))
+        .collect();
+    if days.iter().any(|&(_, d)| d == day) {
+        return Err(format!("{:?} is already there", new_line.trim()));
+    }
+
+    let idx = match days.iter().find(|&&(_, d)| d > day) {
+        Some(&(idx, _)) => idx,
+        None => match days.last() {
+            Some(&(idx, _)) => idx + 1,
+            None => return Err(format!("Nowhere to put {:?}", new_line.trim())),
+        },
+    };
+    lines.insert(idx, new_line);
+    Ok(())
+}
+
+/// Add the module for `day` to the source of `lib.rs`, and add it to `DAYS`
+pub fn register(lib_src: &str, day: u8) -> Result<String, String> {
+    let mut lines: Vec<String> = lib_src.lines().map(String::from).collect();
+
+    insert_in_order(&mut lines, format!("pub mod day{:02};", day), day, |line| {
+        line.strip_prefix("pub mod day")?
+            .strip_suffix(';')?
+            .parse()
+            .ok()
+    })?;
+    insert_in_order(
+        &mut lines,
+        format!("    Day::new::<day{:02}::Day{:02}>(),", day, day),
+        day,
+        |line| {
+            line.trim()
+                .strip_prefix("Day::new::<day")?
+                .get(..2)?
+                .parse()
+                .ok()
+        },
+    )?;
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Create `src/dayNN.rs` and an empty `input/dayNN.txt` under `root`, and register the
+/// day in `src/lib.rs`. Returns every file that was written.
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("There is no day {} in the advent calendar", day));
+    }
+
+    let module_path = root.join("src").join(format!("day{:02}.rs", day));
+    if module_path.exists() {
+        return Err(format!("{} already exists", module_path.display()));
+    }
+
+    let lib_path = root.join("src").join("lib.rs");
+    let lib_src = std::fs::read_to_string(&lib_path)
+        .map_err(|e| format!("Failed to read {}: {}", lib_path.display(), e))?;
+    let lib_src = register(&lib_src, day)?;
+
+    let write = |path: &Path, contents: &str| {
+        std::fs::write(path, contents)
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+    };
+    write(&module_path, &module_source(day))?;
+    write(&lib_path, &lib_src)?;
+    let mut written = vec![module_path, lib_path];
+
+    // Never clobber an input that is already there
+    let input_path = day_file(&root.join("input"), day);
+    if !input_path.exists() {
+        std::fs::create_dir_all(root.join("input"))
+            .map_err(|e| format!("Failed to create {}: {}", root.join("input").display(), e))?;
+        write(&input_path, "")?;
+        written.push(input_path);
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIB: &str = "pub mod bench;
+pub mod day01;
+pub mod day09;
+pub mod error;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day01::Day01>(),
+    Day::new::<day09::Day09>(),
+];
+";
+
+    #[test]
+    fn test_module_source() {
+        let got = module_source(11);
+        assert!(got.contains("pub struct Day11;"));
+        assert!(got.contains("impl Solution for Day11 {"));
+        assert!(got.contains("const DAY: u8 = 11;"));
+        assert!(got.contains("/// TODO: Day 11 part 2"));
+        assert!(!got.contains("todo!"));
+    }
+
+    #[test]
+    fn test_register() {
+        let want = "pub mod bench;
+pub mod day01;
+pub mod day05;
+pub mod day09;
+pub mod error;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day01::Day01>(),
+    Day::new::<day05::Day05>(),
+    Day::new::<day09::Day09>(),
+];
+";
+        assert_eq!(Ok(want.to_string()), register(LIB, 5));
+
+        let got = register(LIB, 12).unwrap();
+        assert!(got.contains("pub mod day09;\npub mod day12;\npub mod error;"));
+        assert!(got.contains("Day09>(),\n    Day::new::<day12::Day12>(),\n];"));
+
+        assert!(register(LIB, 9).is_err());
+        assert!(register("pub mod bench;", 9).is_err());
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = std::env::temp_dir().join(format!("aoc_2022_scaffold_{}", std::process::id()));
+        std::fs::create_dir_all(root.join("src")).unwrap();
+        std::fs::write(root.join("src").join("lib.rs"), LIB).unwrap();
+
+        let got = new_day(&root, 5).unwrap();
+        let want = vec![
+            root.join("src").join("day05.rs"),
+            root.join("src").join("lib.rs"),
+            root.join("input").join("day05.txt"),
+        ];
+        assert_eq!(want, got);
+        assert_eq!("", std::fs::read_to_string(&want[2]).unwrap());
+        assert_eq!(module_source(5), std::fs::read_to_string(&want[0]).unwrap());
+
+        // The day exists now, and days outside the calendar never will
+        assert!(new_day(&root, 5).is_err());
+        assert!(new_day(&root, 26).is_err());
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    /// Copy the files and directories in `names` from `from` into `to`
+    fn copy_tree(from: &Path, to: &Path, names: &[&str]) {
+        for name in names {
+            let src = from.join(name);
+            if src.is_dir() {
+                std::fs::create_dir_all(to.join(name)).unwrap();
+                let entries: Vec<String> = std::fs::read_dir(&src)
+                    .unwrap()
+                    .map(|e| e.unwrap().file_name().into_string().unwrap())
+                    .collect();
+                let entries: Vec<&str> = entries.iter().map(String::as_str).collect();
+                copy_tree(&src, &to.join(name), &entries);
+            } else if src.is_file() {
+                std::fs::copy(&src, to.join(name)).unwrap();
+            }
+        }
+    }
+
+    /// Builds and tests a whole copy of the crate, so it is slow and needs the
+    /// dependencies to be available offline. The copy skips it, as it is ignored.
+    #[test]
+    #[ignore = "Slow: builds and tests a scaffolded copy of the crate"]
+    fn test_scaffolded_crate() {
+        let repo = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let root = std::env::temp_dir().join(format!("aoc_2022_crate_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(&root).unwrap();
+        copy_tree(repo, &root, &["Cargo.toml", "Cargo.lock", "src", "input"]);
+        new_day(&root, 11).unwrap();
+
+        // A separate target directory, so the copy doesn't invalidate this build
+        let cargo = |args: &[&str]| {
+            std::process::Command::new(env!("CARGO"))
+                .args(args)
+                .current_dir(&root)
+                .env(
+                    "CARGO_TARGET_DIR",
+                    repo.join("target").join("scaffold-check"),
+                )
+                .output()
+                .unwrap()
+        };
+        for args in [
+            &["test", "--offline", "--quiet"][..],
+            &["run", "--offline", "--quiet", "--", "verify"][..],
+        ] {
+            let output = cargo(args);
+            assert!(
+                output.status.success(),
+                "cargo {} failed:\n{}{}",
+                args.join(" "),
+                String::from_utf8_lossy(&output.stdout),
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,144 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// The name of the answer key inside an input directory
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+/// The accepted answers for a set of inputs, keyed by day and part.
+///
+/// The file has one answer per line, as `<day> <part> <answer>`. Answers that are drawn
+/// over several lines store each line break as `\n`. Blank lines and lines starting with
+/// `#` are ignored.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AnswerKey {
+    answers: BTreeMap<(u8, u8), String>,
+}
+
+impl AnswerKey {
+    pub fn parse(input_str: &str) -> Result<AnswerKey, String> {
+        let mut answers = BTreeMap::new();
+        for (idx, line) in input_str.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let bad_line = || {
+                format!(
+                    "Answer key line {}: expected '<day> <part> <answer>', found {:?}",
+                    idx + 1,
+                    line
+                )
+            };
+
+            let mut parts = line.splitn(3, ' ');
+            let day: u8 = parts
+                .next()
+                .and_then(|s| s.parse().ok())
+                .ok_or_else(bad_line)?;
+            let part: u8 = parts
+                .next()
+                .and_then(|s| s.parse().ok())
+                .filter(|p| (1..=2).contains(p))
+                .ok_or_else(bad_line)?;
+            let answer = parts.next().ok_or_else(bad_line)?;
+
+            if answers
+                .insert((day, part), answer.replace("\\n", "\n"))
+                .is_some()
+            {
+                return Err(format!(
+                    "Answer key line {}: day {} part {} already has an answer",
+                    idx + 1,
+                    day,
+                    part
+                ));
+            }
+        }
+        Ok(AnswerKey { answers })
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    /// Compare `got` to the accepted answer for `day` and `part`
+    pub fn check(&self, day: u8, part: u8, got: &str) -> Outcome {
+        match self.get(day, part) {
+            Some(want) if want == got => Outcome::Pass,
+            Some(want) => Outcome::Fail {
+                want: want.to_string(),
+                got: got.to_string(),
+            },
+            None => Outcome::Missing,
+        }
+    }
+}
+
+/// How one part of one day compares to its accepted answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Fail {
+        want: String,
+        got: String,
+    },
+    /// There is no accepted answer to compare against
+    Missing,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Pass => write!(f, "pass"),
+            Outcome::Fail { want, got } => write!(
+                f,
+                "FAIL (expected {:?}, got {:?})",
+                want.replace('\n', "\\n"),
+                got.replace('\n', "\\n")
+            ),
+            Outcome::Missing => write!(f, "missing"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &str = "# day part answer
+1 1 24000
+1 2 45000
+
+5 1 CMZ
+10 2 ##..\\n#.#.";
+
+    #[test]
+    fn test_parse() {
+        let key = AnswerKey::parse(KEY).unwrap();
+        assert_eq!(Some("24000"), key.get(1, 1));
+        assert_eq!(Some("CMZ"), key.get(5, 1));
+        assert_eq!(Some("##..\n#.#."), key.get(10, 2));
+        assert_eq!(None, key.get(5, 2));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for bad in ["1 1", "x 1 24000", "1 3 24000", "1 1 24000\n1 1 24001"] {
+            assert!(AnswerKey::parse(bad).is_err(), "{:?} should fail", bad);
+        }
+    }
+
+    #[test]
+    fn test_check() {
+        let key = AnswerKey::parse(KEY).unwrap();
+        assert_eq!(Outcome::Pass, key.check(1, 2, "45000"));
+        assert_eq!(Outcome::Pass, key.check(10, 2, "##..\n#.#."));
+        assert_eq!(
+            Outcome::Fail {
+                want: "CMZ".to_string(),
+                got: "MCD".to_string()
+            },
+            key.check(5, 1, "MCD")
+        );
+        assert_eq!(Outcome::Missing, key.check(9, 2, "36"));
+    }
+}